@@ -0,0 +1,538 @@
+use crate::{EntradaCalculo, ErroCalculo, Money, ResultadoCalculo};
+
+/// Média móvel do `pagamento_final`. Enquanto ainda não há meses
+/// suficientes para preencher a janela, a média usa os meses disponíveis.
+/// Uma janela de tamanho zero resulta em uma série vazia.
+pub fn media_movel(resultados: &[ResultadoCalculo], janela: usize) -> Vec<Money> {
+    if janela == 0 {
+        return Vec::new();
+    }
+
+    (0..resultados.len())
+        .map(|fim| {
+            let inicio = (fim + 1).saturating_sub(janela);
+            let meses = &resultados[inicio..=fim];
+            let soma: i64 = meses.iter().map(|r| r.pagamento_final.to_centavos()).sum();
+            Money::from_centavos(dividir_arredondado(soma, meses.len() as i64))
+        })
+        .collect()
+}
+
+/// Pares (mês, pagamento final em reais) para plotar. O mês vem do
+/// calendário; resultados sem mês usam a posição na série (1, 2, ...).
+pub fn serie_pagamentos(resultados: &[ResultadoCalculo]) -> Vec<(u32, f64)> {
+    resultados
+        .iter()
+        .enumerate()
+        .map(|(indice, resultado)| {
+            let mes = resultado
+                .calendario
+                .as_ref()
+                .and_then(|c| c.mes)
+                .unwrap_or(indice as u32 + 1);
+            (mes, resultado.pagamento_final.to_centavos() as f64 / 100.0)
+        })
+        .collect()
+}
+
+/// Total de vale-transporte recebido nas competências informadas, para a
+/// declaração de imposto de renda.
+pub fn total_anual_transporte(resultados: &[ResultadoCalculo]) -> Money {
+    resultados.iter().map(|r| r.custo_transporte).sum()
+}
+
+// Divisão inteira arredondando o meio centavo para longe do zero
+pub(crate) fn dividir_arredondado(numerador: i64, divisor: i64) -> i64 {
+    let quociente = numerador / divisor;
+    let resto = numerador % divisor;
+
+    if resto.abs() * 2 >= divisor.abs() {
+        quociente + numerador.signum() * divisor.signum()
+    } else {
+        quociente
+    }
+}
+
+/// Calcula cada variação e a diferença do seu pagamento final em relação
+/// ao da base (positiva quando a variação paga mais). Um erro na base ou
+/// em qualquer variação interrompe a comparação.
+pub fn comparar_cenarios(
+    base: &EntradaCalculo,
+    variacoes: &[(String, EntradaCalculo)],
+) -> Result<Vec<(String, ResultadoCalculo, Money)>, ErroCalculo> {
+    let pagamento_base = base.calcular()?.pagamento_final;
+
+    variacoes
+        .iter()
+        .map(|(nome, entrada)| {
+            let resultado = entrada.calcular()?;
+            let delta = resultado.pagamento_final - pagamento_base;
+            Ok((nome.clone(), resultado, delta))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResumoAcumulado {
+    pub pagamento_final: Money,
+    pub custo_transporte: Money,
+    pub deducoes_total: Money,
+    pub dias_trabalhados: i32,
+    pub meses: Vec<(u32, i32)>, // (mês, ano) na ordem em que vieram
+    pub meses_duplicados: Vec<(u32, i32)>,
+    pub meses_faltando: Vec<(u32, i32)>, // entre o primeiro e o último mês
+    pub sem_competencia: usize,          // resultados sem mês/ano no calendário
+}
+
+impl ResumoAcumulado {
+    pub fn tem_avisos(&self) -> bool {
+        !self.meses_duplicados.is_empty()
+            || !self.meses_faltando.is_empty()
+            || self.sem_competencia > 0
+    }
+}
+
+/// Soma vários meses num só resumo, como num fechamento trimestral, e
+/// aponta meses repetidos, lacunas entre o primeiro e o último mês e
+/// resultados sem competência.
+pub fn acumular_resultados(resultados: &[ResultadoCalculo]) -> ResumoAcumulado {
+    let meses: Vec<(u32, i32)> = resultados
+        .iter()
+        .filter_map(|r| r.calendario.as_ref())
+        .filter_map(|c| c.mes.zip(c.ano))
+        .collect();
+
+    let mut ordenados: Vec<(i32, u32)> = meses.iter().map(|&(mes, ano)| (ano, mes)).collect();
+    ordenados.sort();
+    let mut meses_duplicados: Vec<(u32, i32)> = ordenados
+        .windows(2)
+        .filter(|par| par[0] == par[1])
+        .map(|par| (par[0].1, par[0].0))
+        .collect();
+    meses_duplicados.dedup();
+
+    let mut meses_faltando = Vec::new();
+    if let (Some(&primeiro), Some(&ultimo)) = (ordenados.first(), ordenados.last()) {
+        let mut atual = primeiro;
+        while atual < ultimo {
+            if ordenados.binary_search(&atual).is_err() {
+                meses_faltando.push((atual.1, atual.0));
+            }
+            atual = if atual.1 == 12 {
+                (atual.0 + 1, 1)
+            } else {
+                (atual.0, atual.1 + 1)
+            };
+        }
+    }
+
+    ResumoAcumulado {
+        pagamento_final: resultados.iter().map(|r| r.pagamento_final).sum(),
+        custo_transporte: resultados.iter().map(|r| r.custo_transporte).sum(),
+        deducoes_total: resultados.iter().map(|r| r.deducoes_total).sum(),
+        dias_trabalhados: resultados.iter().map(|r| r.dias_trabalhados).sum(),
+        sem_competencia: resultados.len() - meses.len(),
+        meses,
+        meses_duplicados,
+        meses_faltando,
+    }
+}
+
+/// Calcula cada funcionário do lote, na ordem das entradas. Um erro numa
+/// entrada (mês inválido, por exemplo) fica só no resultado dela; as
+/// demais são calculadas normalmente.
+pub fn calcular_lote(entradas: &[EntradaCalculo]) -> Vec<Result<ResultadoCalculo, ErroCalculo>> {
+    entradas.iter().map(EntradaCalculo::calcular).collect()
+}
+
+/// Total a pagar das entradas calculadas com sucesso; as com erro ficam
+/// de fora.
+pub fn total_lote(lote: &[Result<ResultadoCalculo, ErroCalculo>]) -> Money {
+    lote.iter()
+        .filter_map(|resultado| resultado.as_ref().ok())
+        .map(|resultado| resultado.pagamento_final)
+        .sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResumoFolha {
+    pub funcionarios: usize,
+    pub taxa_fixa_total: Money,
+    pub proventos_total: Money,
+    pub custo_transporte_total: Money,
+    pub deducoes_total: Money,
+    pub liquido_total: Money, // soma dos pagamentos finais
+}
+
+/// Consolida a folha da equipe já calculada (por exemplo, as entradas
+/// com sucesso de `calcular_lote`) somando cada componente de todos os
+/// funcionários.
+pub fn consolidar_folha(resultados: &[(String, ResultadoCalculo)]) -> ResumoFolha {
+    let somar = |campo: fn(&ResultadoCalculo) -> Money| -> Money {
+        resultados
+            .iter()
+            .map(|(_, resultado)| campo(resultado))
+            .sum()
+    };
+
+    ResumoFolha {
+        funcionarios: resultados.len(),
+        taxa_fixa_total: somar(|r| r.taxa_fixa),
+        proventos_total: somar(|r| r.proventos_total),
+        custo_transporte_total: somar(|r| r.custo_transporte),
+        deducoes_total: somar(|r| r.deducoes_total),
+        liquido_total: somar(|r| r.pagamento_final),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calcular_valores, Deducao};
+
+    fn resultado_com_pagamento(pagamento: Money) -> ResultadoCalculo {
+        calcular_valores(pagamento, Money::ZERO, 0, vec![])
+    }
+
+    fn serie(valores: &[&str]) -> Vec<ResultadoCalculo> {
+        valores
+            .iter()
+            .map(|v| resultado_com_pagamento(Money::parse(v).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_media_movel_serie_conhecida() {
+        let resultados = serie(&["100", "200", "300", "400", "500"]);
+
+        let medias = media_movel(&resultados, 3);
+
+        assert_eq!(
+            medias,
+            vec![
+                Money::from_reais(100), // só janeiro
+                Money::from_reais(150), // (100 + 200) / 2
+                Money::from_reais(200), // (100 + 200 + 300) / 3
+                Money::from_reais(300), // (200 + 300 + 400) / 3
+                Money::from_reais(400), // (300 + 400 + 500) / 3
+            ]
+        );
+    }
+
+    #[test]
+    fn test_media_movel_arredonda_centavos() {
+        let resultados = serie(&["100", "100", "100.01"]);
+
+        let medias = media_movel(&resultados, 3);
+
+        // 300.01 / 3 = 100.0033... → 100.00
+        assert_eq!(medias[2], Money::from_reais(100));
+
+        let resultados = serie(&["0.01", "0.02"]);
+        // 0.03 / 2 = 0.015 → 0.02
+        assert_eq!(media_movel(&resultados, 2)[1], Money::from_centavos(2));
+    }
+
+    #[test]
+    fn test_media_movel_valores_negativos() {
+        let resultados = serie(&["-0.01", "-0.02"]);
+
+        // -0.03 / 2 = -0.015 → -0.02
+        assert_eq!(media_movel(&resultados, 2)[1], Money::from_centavos(-2));
+    }
+
+    #[test]
+    fn test_media_movel_janela_maior_que_serie() {
+        let resultados = serie(&["100", "300"]);
+
+        let medias = media_movel(&resultados, 12);
+
+        assert_eq!(medias, vec![Money::from_reais(100), Money::from_reais(200)]);
+    }
+
+    #[test]
+    fn test_media_movel_casos_vazios() {
+        assert!(media_movel(&[], 3).is_empty());
+        assert!(media_movel(&serie(&["100"]), 0).is_empty());
+    }
+
+    fn entrada_marco_2024(feriados_deduzidos: i32) -> EntradaCalculo {
+        EntradaCalculo {
+            nome: String::new(),
+            taxa_fixa: Money::from_reais(150),
+            taxa_transporte: Money::parse("7.50").unwrap(),
+            mes: 3,
+            ano: 2024,
+            feriados_deduzidos,
+            deducoes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_comparar_cenarios_deltas() {
+        // Março de 2024 tem 21 dias úteis
+        let base = entrada_marco_2024(0);
+        let variacoes = vec![
+            ("3 dias remotos".to_string(), entrada_marco_2024(3)),
+            (
+                "Com desconto".to_string(),
+                EntradaCalculo {
+                    deducoes: vec![Deducao {
+                        valor: Money::from_reais(20),
+                        descricao: "Refeição".to_string(),
+                    }],
+                    ..entrada_marco_2024(0)
+                },
+            ),
+        ];
+
+        let comparacao = comparar_cenarios(&base, &variacoes).unwrap();
+
+        assert_eq!(comparacao.len(), 2);
+        assert_eq!(comparacao[0].0, "3 dias remotos");
+        assert_eq!(comparacao[0].1.dias_trabalhados, 18);
+        // 3 dias × 2 viagens × 7.50
+        assert_eq!(comparacao[0].2, Money::parse("-45.00").unwrap());
+        assert_eq!(comparacao[1].0, "Com desconto");
+        assert_eq!(comparacao[1].2, Money::parse("-20.00").unwrap());
+    }
+
+    #[test]
+    fn test_comparar_cenarios_erros() {
+        let base = entrada_marco_2024(0);
+        let invalida = EntradaCalculo {
+            mes: 13,
+            ..entrada_marco_2024(0)
+        };
+
+        assert!(comparar_cenarios(&base, &[]).unwrap().is_empty());
+        assert_eq!(
+            comparar_cenarios(&base, &[("Inválida".to_string(), invalida.clone())]).unwrap_err(),
+            ErroCalculo::MesInvalido(13)
+        );
+        assert_eq!(
+            comparar_cenarios(&invalida, &[]).unwrap_err(),
+            ErroCalculo::MesInvalido(13)
+        );
+    }
+
+    fn competencias(meses: &[(u32, i32)]) -> Vec<ResultadoCalculo> {
+        meses
+            .iter()
+            .map(|&(mes, ano)| {
+                EntradaCalculo {
+                    mes,
+                    ano,
+                    ..entrada_marco_2024(0)
+                }
+                .calcular()
+                .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_acumular_trimestre() {
+        // Janeiro, fevereiro e março de 2024: 23, 21 e 21 dias úteis
+        let resumo = acumular_resultados(&competencias(&[(1, 2024), (2, 2024), (3, 2024)]));
+
+        assert_eq!(resumo.dias_trabalhados, 65);
+        // 65 × 2 × 7.50 = 975.00, mais 3 × 150.00
+        assert_eq!(resumo.custo_transporte, Money::from_reais(975));
+        assert_eq!(resumo.pagamento_final, Money::from_reais(1425));
+        assert_eq!(resumo.deducoes_total, Money::ZERO);
+        assert_eq!(resumo.meses, vec![(1, 2024), (2, 2024), (3, 2024)]);
+        assert!(!resumo.tem_avisos());
+    }
+
+    #[test]
+    fn test_acumular_meses_duplicados_e_faltando() {
+        let resumo = acumular_resultados(&competencias(&[
+            (11, 2024),
+            (2, 2025),
+            (11, 2024),
+            (1, 2025),
+        ]));
+
+        assert!(resumo.tem_avisos());
+        assert_eq!(resumo.meses_duplicados, vec![(11, 2024)]);
+        // A lacuna atravessa a virada do ano
+        assert_eq!(resumo.meses_faltando, vec![(12, 2024)]);
+    }
+
+    #[test]
+    fn test_acumular_sem_competencia() {
+        let mut resultados = serie(&["100", "200"]);
+        resultados.extend(competencias(&[(3, 2024)]));
+
+        let resumo = acumular_resultados(&resultados);
+
+        assert_eq!(resumo.sem_competencia, 2);
+        assert_eq!(resumo.meses, vec![(3, 2024)]);
+        assert_eq!(resumo.pagamento_final, Money::from_reais(765));
+        assert!(resumo.tem_avisos());
+        assert_eq!(acumular_resultados(&[]).pagamento_final, Money::ZERO);
+    }
+
+    #[test]
+    fn test_calcular_lote_com_erro_isolado() {
+        let entradas = vec![
+            EntradaCalculo {
+                nome: "Ana".to_string(),
+                ..entrada_marco_2024(0)
+            },
+            EntradaCalculo {
+                nome: "Bruno".to_string(),
+                mes: 13,
+                ..entrada_marco_2024(0)
+            },
+            EntradaCalculo {
+                nome: "Carla".to_string(),
+                taxa_transporte: Money::parse("4.40").unwrap(),
+                ..entrada_marco_2024(1)
+            },
+        ];
+
+        let lote = calcular_lote(&entradas);
+
+        assert_eq!(lote.len(), 3);
+        assert_eq!(lote[1].as_ref().unwrap_err(), &ErroCalculo::MesInvalido(13));
+        // 150 + 21 × 2 × 7.50 = 465.00 e 150 + 20 × 2 × 4.40 = 326.00
+        assert_eq!(
+            lote[0].as_ref().unwrap().pagamento_final,
+            Money::from_reais(465)
+        );
+        assert_eq!(total_lote(&lote), Money::from_reais(791));
+    }
+
+    #[test]
+    fn test_calcular_lote_vazio() {
+        let lote = calcular_lote(&[]);
+
+        assert!(lote.is_empty());
+        assert_eq!(total_lote(&lote), Money::ZERO);
+    }
+
+    #[test]
+    fn test_serie_pagamentos_com_calendario() {
+        let resultados: Vec<ResultadoCalculo> = [3, 4, 5]
+            .iter()
+            .map(|&mes| EntradaCalculo {
+                mes,
+                ..entrada_marco_2024(0)
+            })
+            .map(|entrada| entrada.calcular().unwrap())
+            .collect();
+
+        // 150 + dias úteis × 2 × 7.50 (21, 22 e 23 dias úteis)
+        assert_eq!(
+            serie_pagamentos(&resultados),
+            vec![(3, 465.0), (4, 480.0), (5, 495.0)]
+        );
+    }
+
+    #[test]
+    fn test_serie_pagamentos_sem_calendario_usa_posicao() {
+        let resultados = serie(&["100.50", "-20", "0"]);
+
+        assert_eq!(
+            serie_pagamentos(&resultados),
+            vec![(1, 100.5), (2, -20.0), (3, 0.0)]
+        );
+        assert!(serie_pagamentos(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_total_anual_transporte() {
+        // (tarifa, dias trabalhados) de janeiro a dezembro, com reajuste
+        // em abril e setembro, mês de férias e mês com afastamento
+        let competencias = [
+            ("4.40", 22),
+            ("4.40", 20),
+            ("4.40", 21),
+            ("5.00", 22),
+            ("5.00", 0),
+            ("5.00", 21),
+            ("5.00", 23),
+            ("5.00", 22),
+            ("5.25", 21),
+            ("5.25", 23),
+            ("5.25", 20),
+            ("5.25", 15),
+        ];
+        let resultados: Vec<ResultadoCalculo> = competencias
+            .iter()
+            .map(|&(tarifa, dias)| {
+                calcular_valores(
+                    Money::from_reais(1500),
+                    Money::parse(tarifa).unwrap(),
+                    dias,
+                    vec![Deducao {
+                        valor: Money::from_reais(50),
+                        descricao: "Adiantamento".to_string(),
+                    }],
+                )
+            })
+            .collect();
+
+        // Só o transporte entra: taxa fixa e deduções ficam de fora
+        assert_eq!(
+            total_anual_transporte(&resultados),
+            Money::parse("2263.90").unwrap()
+        );
+        assert_eq!(total_anual_transporte(&[]), Money::ZERO);
+    }
+
+    #[test]
+    fn test_consolidar_folha() {
+        let mut ana = calcular_valores(Money::from_reais(2000), Money::from_reais(5), 20, vec![]);
+        ana.adicionar_provento(crate::Provento {
+            valor: Money::from_reais(150),
+            descricao: "Bônus".to_string(),
+        });
+        let bruno = calcular_valores(
+            Money::from_reais(1800),
+            Money::parse("4.40").unwrap(),
+            18,
+            vec![Deducao {
+                valor: Money::from_reais(120),
+                descricao: "Adiantamento".to_string(),
+            }],
+        );
+        let carla = calcular_valores(
+            Money::from_reais(2500),
+            Money::ZERO,
+            22,
+            vec![Deducao {
+                valor: Money::parse("80.50").unwrap(),
+                descricao: String::new(),
+            }],
+        );
+        let equipe = vec![
+            ("Ana".to_string(), ana),
+            ("Bruno".to_string(), bruno),
+            ("Carla".to_string(), carla),
+        ];
+
+        let folha = consolidar_folha(&equipe);
+
+        assert_eq!(folha.funcionarios, 3);
+        assert_eq!(folha.taxa_fixa_total, Money::from_reais(6300));
+        assert_eq!(folha.proventos_total, Money::from_reais(150));
+        // 5 × 20 × 2 + 4.40 × 18 × 2
+        assert_eq!(
+            folha.custo_transporte_total,
+            Money::parse("358.40").unwrap()
+        );
+        assert_eq!(folha.deducoes_total, Money::parse("200.50").unwrap());
+        assert_eq!(folha.liquido_total, Money::parse("6607.90").unwrap());
+    }
+
+    #[test]
+    fn test_consolidar_folha_vazia() {
+        let folha = consolidar_folha(&[]);
+
+        assert_eq!(folha.funcionarios, 0);
+        assert_eq!(folha.liquido_total, Money::ZERO);
+    }
+}
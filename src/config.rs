@@ -0,0 +1,509 @@
+use crate::{validar_mes_ano, Deducao, EntradaCalculo, ErroCalculo, Money};
+use chrono::{Datelike, Local, NaiveDate};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Lê a entrada das variáveis `CALC_TAXA_FIXA`, `CALC_TAXA_TRANSPORTE`,
+/// `CALC_MES`, `CALC_ANO` e `CALC_FERIADOS` do ambiente, com o mês e o
+/// ano de hoje como padrão. Para que os argumentos de linha de comando
+/// tenham precedência, use `ler_config_parcial` com
+/// `ArgumentosCli::com_padroes_de`, como a CLI.
+pub fn ler_config_env() -> Result<EntradaCalculo, ErroCalculo> {
+    ler_config(&std::env::vars().collect(), Local::now().date_naive())
+}
+
+/// Como `ler_config_env`, a partir de um mapa de variáveis. Valores
+/// monetários ausentes são zero, mês e ano ausentes são os de `hoje` e os
+/// feriados ausentes são zero. `hoje` vem de fora para que o relógio
+/// possa ser fixado em testes.
+pub fn ler_config(
+    variaveis: &HashMap<String, String>,
+    hoje: NaiveDate,
+) -> Result<EntradaCalculo, ErroCalculo> {
+    let lidos = ler_config_parcial(variaveis)?;
+
+    let (mes, ano) = (
+        lidos.mes.unwrap_or(hoje.month()),
+        lidos.ano.unwrap_or(hoje.year()),
+    );
+    validar_mes_ano(mes, ano)?;
+
+    Ok(EntradaCalculo {
+        nome: String::new(),
+        taxa_fixa: lidos.taxa_fixa.unwrap_or(Money::ZERO),
+        taxa_transporte: lidos.taxa_transporte.unwrap_or(Money::ZERO),
+        mes,
+        ano,
+        feriados_deduzidos: lidos.feriados.unwrap_or(0),
+        deducoes: vec![],
+    })
+}
+
+/// Só as variáveis presentes no mapa, sem padrões, para combinar com os
+/// argumentos de linha de comando. A CLI pergunta apenas o que não veio
+/// de nenhum dos dois.
+pub fn ler_config_parcial(
+    variaveis: &HashMap<String, String>,
+) -> Result<ArgumentosCli, ErroCalculo> {
+    Ok(ArgumentosCli {
+        taxa_fixa: ler_variavel(variaveis, "CALC_TAXA_FIXA", |v| Money::parse(v).ok())?,
+        taxa_transporte: ler_variavel(variaveis, "CALC_TAXA_TRANSPORTE", |v| Money::parse(v).ok())?,
+        mes: ler_variavel(variaveis, "CALC_MES", |v| v.trim().parse().ok())?,
+        ano: ler_variavel(variaveis, "CALC_ANO", |v| v.trim().parse().ok())?,
+        feriados: ler_variavel(variaveis, "CALC_FERIADOS", |v| v.trim().parse().ok())?,
+        deducoes: None,
+    })
+}
+
+// Ausente é `None`; presente mas inválida é erro com o nome da variável
+fn ler_variavel<T>(
+    variaveis: &HashMap<String, String>,
+    nome: &str,
+    converter: impl Fn(&str) -> Option<T>,
+) -> Result<Option<T>, ErroCalculo> {
+    variaveis
+        .get(nome)
+        .map(|valor| {
+            converter(valor).ok_or_else(|| ErroCalculo::VariavelInvalida(nome.to_string()))
+        })
+        .transpose()
+}
+
+/// Valores passados na linha de comando (ou nas variáveis `CALC_*`). Os
+/// ausentes são pedidos no prompt, a menos que o modo seja não-interativo.
+#[derive(Debug, Clone, Default)]
+pub struct ArgumentosCli {
+    pub taxa_fixa: Option<Money>,
+    pub taxa_transporte: Option<Money>,
+    pub mes: Option<u32>,
+    pub ano: Option<i32>,
+    pub feriados: Option<i32>,
+    pub deducoes: Option<Vec<Deducao>>, // uma por `--deducoes`
+}
+
+impl ArgumentosCli {
+    /// Com taxas, mês e ano informados nada é perguntado: sem
+    /// `--feriados` não há feriados e sem `--deducoes` não há deduções.
+    pub fn nao_interativo(&self) -> bool {
+        self.taxa_fixa.is_some()
+            && self.taxa_transporte.is_some()
+            && self.mes.is_some()
+            && self.ano.is_some()
+    }
+
+    /// Completa o que não veio em `self` com os valores de `base`; o
+    /// que veio em `self` tem precedência.
+    pub fn com_padroes_de(self, base: ArgumentosCli) -> ArgumentosCli {
+        ArgumentosCli {
+            taxa_fixa: self.taxa_fixa.or(base.taxa_fixa),
+            taxa_transporte: self.taxa_transporte.or(base.taxa_transporte),
+            mes: self.mes.or(base.mes),
+            ano: self.ano.or(base.ano),
+            feriados: self.feriados.or(base.feriados),
+            deducoes: self.deducoes.or(base.deducoes),
+        }
+    }
+
+    pub fn para_entrada(&self) -> Option<EntradaCalculo> {
+        if !self.nao_interativo() {
+            return None;
+        }
+
+        Some(EntradaCalculo {
+            nome: String::new(),
+            taxa_fixa: self.taxa_fixa?,
+            taxa_transporte: self.taxa_transporte?,
+            mes: self.mes?,
+            ano: self.ano?,
+            feriados_deduzidos: self.feriados.unwrap_or(0),
+            deducoes: self.deducoes.clone().unwrap_or_default(),
+        })
+    }
+}
+
+/// Lê `--taxa-fixa`, `--taxa-transporte`, `--mes`, `--ano`, `--feriados`
+/// e `--deducoes` (repetível), como `--mes 11` ou `--mes=11`. `--cores` e
+/// `--brl` são tratadas pelo binário e ignoradas aqui; qualquer outra
+/// opção é `ArgumentoInvalido`, para que um erro de digitação não caia
+/// nos prompts interativos.
+pub fn ler_argumentos(
+    argumentos: impl IntoIterator<Item = String>,
+) -> Result<ArgumentosCli, ErroCalculo> {
+    let mut lidos = ArgumentosCli::default();
+    let mut argumentos = argumentos.into_iter();
+
+    while let Some(argumento) = argumentos.next() {
+        let (opcao, valor) = match argumento.split_once('=') {
+            Some((opcao, valor)) => (opcao.to_string(), Some(valor.to_string())),
+            None => (argumento, None),
+        };
+        if OPCOES_SEM_VALOR.contains(&opcao.as_str()) {
+            continue;
+        }
+        if !OPCOES_COM_VALOR.contains(&opcao.as_str()) {
+            return Err(ErroCalculo::ArgumentoInvalido(opcao));
+        }
+
+        let valor = valor
+            .or_else(|| argumentos.next())
+            .ok_or_else(|| ErroCalculo::ArgumentoInvalido(opcao.clone()))?;
+        let invalido = || ErroCalculo::ArgumentoInvalido(opcao.clone());
+        let dinheiro = || Money::parse(&valor).map_err(|_| invalido());
+
+        match opcao.as_str() {
+            "--taxa-fixa" => lidos.taxa_fixa = Some(dinheiro()?),
+            "--taxa-transporte" => lidos.taxa_transporte = Some(dinheiro()?),
+            "--mes" => lidos.mes = Some(valor.trim().parse().map_err(|_| invalido())?),
+            "--ano" => lidos.ano = Some(valor.trim().parse().map_err(|_| invalido())?),
+            "--feriados" => {
+                let feriados: i32 = valor.trim().parse().map_err(|_| invalido())?;
+                if feriados < 0 {
+                    return Err(invalido());
+                }
+                lidos.feriados = Some(feriados);
+            }
+            "--deducoes" => {
+                let valor = dinheiro()?;
+                let deducoes = lidos.deducoes.get_or_insert_with(Vec::new);
+                if valor != Money::ZERO {
+                    deducoes.push(Deducao {
+                        valor,
+                        descricao: String::new(),
+                    });
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(lidos)
+}
+
+const OPCOES_SEM_VALOR: [&str; 2] = ["--cores", "--brl"];
+
+const OPCOES_COM_VALOR: [&str; 6] = [
+    "--taxa-fixa",
+    "--taxa-transporte",
+    "--mes",
+    "--ano",
+    "--feriados",
+    "--deducoes",
+];
+
+/// Taxas sugeridas nos prompts da CLI, lidas de um arquivo TOML simples:
+///
+/// ```toml
+/// taxa_fixa = "1500.00"
+/// taxa_transporte = 4.40
+/// ```
+///
+/// Ausentes ficam `None` e o prompt não sugere nada.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfiguracaoPadrao {
+    pub taxa_fixa: Option<Money>,
+    pub taxa_transporte: Option<Money>,
+}
+
+impl ConfiguracaoPadrao {
+    /// Lê o arquivo em `caminho`; se ele não existir, não há padrões.
+    /// Aceita só linhas `chave = valor` (valor com ou sem aspas),
+    /// comentários com `#` e linhas em branco; chaves desconhecidas são
+    /// ignoradas.
+    pub fn carregar(caminho: &Path) -> Result<ConfiguracaoPadrao, ErroCalculo> {
+        match std::fs::read_to_string(caminho) {
+            Ok(conteudo) => Self::interpretar(&conteudo),
+            Err(erro) if erro.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(_) => Err(ErroCalculo::ArquivoIlegivel(caminho.display().to_string())),
+        }
+    }
+
+    /// Como `carregar`, a partir de `~/.calculadora.toml`.
+    pub fn carregar_do_usuario() -> Result<ConfiguracaoPadrao, ErroCalculo> {
+        match caminho_do_usuario() {
+            Some(caminho) => Self::carregar(&caminho),
+            None => Ok(Self::default()),
+        }
+    }
+
+    fn interpretar(conteudo: &str) -> Result<ConfiguracaoPadrao, ErroCalculo> {
+        let mut configuracao = Self::default();
+
+        for (indice, linha) in conteudo.lines().enumerate() {
+            let invalida = || ErroCalculo::ConfiguracaoInvalida { linha: indice + 1 };
+
+            let linha = linha.split('#').next().unwrap_or_default().trim();
+            if linha.is_empty() {
+                continue;
+            }
+
+            // `Money::parse` já remove as aspas do valor
+            let (chave, valor) = linha.split_once('=').ok_or_else(invalida)?;
+
+            let destino = match chave.trim() {
+                "taxa_fixa" => &mut configuracao.taxa_fixa,
+                "taxa_transporte" => &mut configuracao.taxa_transporte,
+                _ => continue,
+            };
+            *destino = Some(Money::parse(valor).map_err(|_| invalida())?);
+        }
+
+        Ok(configuracao)
+    }
+}
+
+fn caminho_do_usuario() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".calculadora.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variaveis(pares: &[(&str, &str)]) -> HashMap<String, String> {
+        pares
+            .iter()
+            .map(|(nome, valor)| (nome.to_string(), valor.to_string()))
+            .collect()
+    }
+
+    fn hoje() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, 14).unwrap()
+    }
+
+    fn argumentos(texto: &str) -> Vec<String> {
+        texto.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_ler_argumentos_completos() {
+        let lidos = ler_argumentos(argumentos(
+            "--cores --taxa-fixa 150 --taxa-transporte=7,50 --mes 11 --ano 2024 \
+             --feriados 2 --deducoes 25 --deducoes 0 --deducoes 10.5",
+        ))
+        .unwrap();
+
+        assert!(lidos.nao_interativo());
+        let entrada = lidos.para_entrada().unwrap();
+        assert_eq!(entrada.taxa_transporte, Money::parse("7.50").unwrap());
+        assert_eq!(entrada.feriados_deduzidos, 2);
+        let deducoes: Vec<Money> = entrada.deducoes.iter().map(|d| d.valor).collect();
+        assert_eq!(
+            deducoes,
+            [Money::from_reais(25), Money::parse("10.50").unwrap()]
+        );
+        // 150 + 19 × 2 × 7.50 - 35.50
+        assert_eq!(
+            entrada.calcular().unwrap().pagamento_final,
+            Money::parse("399.50").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ler_argumentos_parciais() {
+        let lidos = ler_argumentos(argumentos("--taxa-fixa 150 --mes 11")).unwrap();
+
+        assert!(!lidos.nao_interativo());
+        assert!(lidos.para_entrada().is_none());
+        assert_eq!(lidos.taxa_transporte, None);
+        assert!(lidos.deducoes.is_none());
+
+        let sem_opcionais = ler_argumentos(argumentos(
+            "--taxa-fixa 1 --taxa-transporte 1 --mes 1 --ano 2024",
+        ))
+        .unwrap()
+        .para_entrada()
+        .unwrap();
+        assert_eq!(sem_opcionais.feriados_deduzidos, 0);
+        assert!(sem_opcionais.deducoes.is_empty());
+    }
+
+    #[test]
+    fn test_ler_argumentos_invalidos() {
+        assert_eq!(
+            ler_argumentos(argumentos("--mes onze")).unwrap_err(),
+            ErroCalculo::ArgumentoInvalido("--mes".to_string())
+        );
+        assert_eq!(
+            ler_argumentos(argumentos("--taxa-fixa")).unwrap_err(),
+            ErroCalculo::ArgumentoInvalido("--taxa-fixa".to_string())
+        );
+        assert_eq!(
+            ler_argumentos(argumentos("--feriados -1")).unwrap_err(),
+            ErroCalculo::ArgumentoInvalido("--feriados".to_string())
+        );
+        assert_eq!(
+            ler_argumentos(argumentos("--deducoes=abc"))
+                .unwrap_err()
+                .to_string(),
+            "Valor inválido para --deducoes"
+        );
+    }
+
+    #[test]
+    fn test_ler_argumentos_opcao_desconhecida() {
+        assert_eq!(
+            ler_argumentos(argumentos("--taxa-fixo 150 --mes 11")).unwrap_err(),
+            ErroCalculo::ArgumentoInvalido("--taxa-fixo".to_string())
+        );
+        assert_eq!(
+            ler_argumentos(argumentos("--mes 11 extra")).unwrap_err(),
+            ErroCalculo::ArgumentoInvalido("extra".to_string())
+        );
+        assert!(ler_argumentos(argumentos("--cores --brl --mes=11")).is_ok());
+    }
+
+    #[test]
+    fn test_ler_config_completa() {
+        let entrada = ler_config(
+            &variaveis(&[
+                ("CALC_TAXA_FIXA", "150.00"),
+                ("CALC_TAXA_TRANSPORTE", "7.5"),
+                ("CALC_MES", "11"),
+                ("CALC_ANO", "2024"),
+                ("CALC_FERIADOS", " 2 "),
+            ]),
+            hoje(),
+        )
+        .unwrap();
+
+        assert_eq!(entrada.taxa_fixa, Money::from_reais(150));
+        assert_eq!(entrada.taxa_transporte, Money::parse("7.50").unwrap());
+        assert_eq!((entrada.mes, entrada.ano), (11, 2024));
+        assert_eq!(entrada.feriados_deduzidos, 2);
+        assert!(entrada.deducoes.is_empty());
+        assert_eq!(
+            entrada.calcular().unwrap().pagamento_final,
+            Money::from_reais(435)
+        );
+    }
+
+    #[test]
+    fn test_ler_config_padroes() {
+        let entrada = ler_config(&HashMap::new(), hoje()).unwrap();
+
+        assert_eq!(entrada.taxa_fixa, Money::ZERO);
+        assert_eq!(entrada.taxa_transporte, Money::ZERO);
+        assert_eq!((entrada.mes, entrada.ano), (3, 2025));
+        assert_eq!(entrada.feriados_deduzidos, 0);
+
+        // Só o ano informado: o mês continua sendo o de hoje
+        let entrada = ler_config(&variaveis(&[("CALC_ANO", "2024")]), hoje()).unwrap();
+        assert_eq!((entrada.mes, entrada.ano), (3, 2024));
+    }
+
+    #[test]
+    fn test_argumentos_tem_precedencia_sobre_ambiente() {
+        let ambiente = ler_config_parcial(&variaveis(&[
+            ("CALC_TAXA_FIXA", "150.00"),
+            ("CALC_TAXA_TRANSPORTE", "7.5"),
+            ("CALC_MES", "11"),
+            ("CALC_ANO", "2024"),
+        ]))
+        .unwrap();
+
+        let lidos = ler_argumentos(argumentos("--taxa-fixa 200 --mes 10"))
+            .unwrap()
+            .com_padroes_de(ambiente);
+
+        assert!(lidos.nao_interativo());
+        let entrada = lidos.para_entrada().unwrap();
+        assert_eq!(entrada.taxa_fixa, Money::from_reais(200));
+        assert_eq!(entrada.taxa_transporte, Money::parse("7.50").unwrap());
+        assert_eq!((entrada.mes, entrada.ano), (10, 2024));
+    }
+
+    #[test]
+    fn test_ler_config_parcial_so_presentes() {
+        let lidos =
+            ler_config_parcial(&variaveis(&[("CALC_MES", "3"), ("CALC_FERIADOS", "0")])).unwrap();
+
+        assert_eq!(lidos.mes, Some(3));
+        assert_eq!(lidos.feriados, Some(0));
+        assert_eq!(lidos.taxa_fixa, None);
+        assert_eq!(lidos.ano, None);
+        assert!(lidos.deducoes.is_none());
+        assert!(!lidos.nao_interativo());
+        assert_eq!(
+            ler_config_parcial(&variaveis(&[("CALC_ANO", "dois mil")])).unwrap_err(),
+            ErroCalculo::VariavelInvalida("CALC_ANO".to_string())
+        );
+    }
+
+    #[test]
+    fn test_ler_config_valores_invalidos() {
+        assert_eq!(
+            ler_config(&variaveis(&[("CALC_TAXA_FIXA", "abc")]), hoje()).unwrap_err(),
+            ErroCalculo::VariavelInvalida("CALC_TAXA_FIXA".to_string())
+        );
+        assert_eq!(
+            ler_config(&variaveis(&[("CALC_FERIADOS", "dois")]), hoje()).unwrap_err(),
+            ErroCalculo::VariavelInvalida("CALC_FERIADOS".to_string())
+        );
+        assert_eq!(
+            ler_config(&variaveis(&[("CALC_MES", "13")]), hoje()).unwrap_err(),
+            ErroCalculo::MesInvalido(13)
+        );
+        assert_eq!(
+            ler_config(&variaveis(&[("CALC_ANO", "1850")]), hoje()).unwrap_err(),
+            ErroCalculo::AnoInvalido(1850)
+        );
+    }
+
+    fn arquivo_temporario(nome: &str, conteudo: &str) -> PathBuf {
+        let caminho =
+            std::env::temp_dir().join(format!("calculadora_{}_{}.toml", nome, std::process::id()));
+        std::fs::write(&caminho, conteudo).unwrap();
+        caminho
+    }
+
+    #[test]
+    fn test_configuracao_padrao_carregar() {
+        let caminho = arquivo_temporario(
+            "configuracao_valida",
+            "# taxas do contrato\ntaxa_fixa = \"1500.00\"\n\ntaxa_transporte = 4.40 # ônibus\nmoeda = \"BRL\"\n",
+        );
+
+        let configuracao = ConfiguracaoPadrao::carregar(&caminho);
+        std::fs::remove_file(&caminho).unwrap();
+
+        assert_eq!(
+            configuracao.unwrap(),
+            ConfiguracaoPadrao {
+                taxa_fixa: Some(Money::from_reais(1500)),
+                taxa_transporte: Some(Money::parse("4.40").unwrap()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_configuracao_padrao_arquivo_ausente() {
+        let caminho = std::env::temp_dir().join("calculadora_nao_existe.toml");
+
+        assert_eq!(
+            ConfiguracaoPadrao::carregar(&caminho).unwrap(),
+            ConfiguracaoPadrao::default()
+        );
+    }
+
+    #[test]
+    fn test_configuracao_padrao_valores_invalidos() {
+        let caminho = arquivo_temporario(
+            "configuracao_invalida",
+            "taxa_fixa = 1500\ntaxa_transporte = \"quatro\"\n",
+        );
+        let erro = ConfiguracaoPadrao::carregar(&caminho).unwrap_err();
+        std::fs::remove_file(&caminho).unwrap();
+
+        assert_eq!(erro, ErroCalculo::ConfiguracaoInvalida { linha: 2 });
+        assert_eq!(
+            erro.to_string(),
+            "Linha 2 inválida no arquivo de configuração"
+        );
+
+        let caminho = arquivo_temporario("configuracao_sem_igual", "taxa_fixa 1500\n");
+        let erro = ConfiguracaoPadrao::carregar(&caminho).unwrap_err();
+        std::fs::remove_file(&caminho).unwrap();
+
+        assert_eq!(erro, ErroCalculo::ConfiguracaoInvalida { linha: 1 });
+    }
+}
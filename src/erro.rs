@@ -0,0 +1,105 @@
+use crate::Money;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErroCalculo {
+    MesInvalido(u32),
+    AnoInvalido(i32),
+    DataInvalida,
+    EntradaSaida(String),
+    PeriodoInvalido,
+    CentavosInvalidos(u8),
+    DivisorInvalido(i32),
+    VariavelInvalida(String),  // nome da variável de ambiente
+    ArgumentoInvalido(String), // opção da linha de comando
+    DescontosAcimaDoLimite { permitido: Money, solicitado: Money },
+    ParseMoney(ErroParseMoney),
+    CompetenciaDistante { meses_a_frente: i32, maximo: i32 },
+    ArquivoIlegivel(String),               // caminho do arquivo
+    ConfiguracaoInvalida { linha: usize }, // numerada a partir de 1
+}
+
+impl fmt::Display for ErroCalculo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErroCalculo::MesInvalido(_) => write!(f, "Mês deve estar entre 1 e 12"),
+            ErroCalculo::AnoInvalido(_) => write!(f, "Ano deve estar entre 1900 e 2100"),
+            ErroCalculo::DataInvalida => write!(f, "Data inválida"),
+            ErroCalculo::EntradaSaida(erro) => write!(f, "Falha de entrada/saída: {}", erro),
+            ErroCalculo::PeriodoInvalido => write!(f, "Data final anterior à inicial"),
+            ErroCalculo::CentavosInvalidos(_) => write!(f, "Centavos devem estar entre 0 e 99"),
+            ErroCalculo::DivisorInvalido(_) => write!(f, "Divisor de dias deve ser positivo"),
+            ErroCalculo::VariavelInvalida(nome) => write!(f, "Valor inválido em {}", nome),
+            ErroCalculo::ArgumentoInvalido(opcao) => write!(f, "Valor inválido para {}", opcao),
+            ErroCalculo::DescontosAcimaDoLimite {
+                permitido,
+                solicitado,
+            } => write!(
+                f,
+                "Descontos de R$ {} excedem o limite de R$ {}",
+                solicitado, permitido
+            ),
+            ErroCalculo::ParseMoney(erro) => write!(f, "Valor monetário inválido: {}", erro),
+            ErroCalculo::CompetenciaDistante {
+                meses_a_frente,
+                maximo,
+            } => write!(
+                f,
+                "Competência {} meses à frente excede o limite de {}",
+                meses_a_frente, maximo
+            ),
+            ErroCalculo::ArquivoIlegivel(caminho) => {
+                write!(f, "Não foi possível ler o arquivo {}", caminho)
+            }
+            ErroCalculo::ConfiguracaoInvalida { linha } => {
+                write!(f, "Linha {} inválida no arquivo de configuração", linha)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ErroCalculo {}
+
+// Permite usar `?` sobre `Money::parse` em funções que retornam `ErroCalculo`
+impl From<ErroParseMoney> for ErroCalculo {
+    fn from(erro: ErroParseMoney) -> Self {
+        ErroCalculo::ParseMoney(erro)
+    }
+}
+
+// Erros de `Money::parse`, um por caso
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErroParseMoney {
+    NumeroInvalido,
+    ParteReaisInvalida,
+    ParteCentavosInvalida,
+    MuitasCasasDecimais,
+    SeparadorMilharInvalido,
+    ParentesesDesbalanceados,
+    NotacaoContabilInvalida,
+    AspasDesbalanceadas,
+    AspaInesperada,
+    ValorAusenteAposSimbolo,
+    SinalDuplicado,
+}
+
+impl fmt::Display for ErroParseMoney {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mensagem = match self {
+            ErroParseMoney::NumeroInvalido => "Número inválido",
+            ErroParseMoney::ParteReaisInvalida => "Parte dos reais inválida",
+            ErroParseMoney::ParteCentavosInvalida => "Parte dos centavos inválida",
+            ErroParseMoney::MuitasCasasDecimais => "Casas decimais demais",
+            ErroParseMoney::SeparadorMilharInvalido => "Separador de milhar inválido",
+            ErroParseMoney::ParentesesDesbalanceados => "Parênteses desbalanceados",
+            ErroParseMoney::NotacaoContabilInvalida => "Notação contábil inválida",
+            ErroParseMoney::AspasDesbalanceadas => "Aspas desbalanceadas",
+            ErroParseMoney::AspaInesperada => "Aspa inesperada",
+            ErroParseMoney::ValorAusenteAposSimbolo => "Valor ausente depois de R$",
+            ErroParseMoney::SinalDuplicado => "Sinal duplicado",
+        };
+        write!(f, "{}", mensagem)
+    }
+}
+
+impl std::error::Error for ErroParseMoney {}
@@ -0,0 +1,382 @@
+use crate::{InformacaoCalendario, ResultadoCalculo};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Colunas do CSV, sempre nesta ordem. As do calendário ficam vazias
+/// quando o resultado não tem calendário.
+pub const CABECALHO_CSV: [&str; 14] = [
+    "taxa_fixa",
+    "taxa_transporte",
+    "dias_trabalhados",
+    "viagens_por_dia",
+    "custo_transporte",
+    "proventos_total",
+    "deducoes_total",
+    "pagamento_final",
+    "mes",
+    "ano",
+    "nome_mes",
+    "dias_uteis_mes",
+    "feriados_deduzidos",
+    "dias_trabalhados_calendario",
+];
+
+/// Cabeçalho e uma linha de valores, com os valores monetários em duas
+/// casas ("1234.50"). Deduções e proventos entram só pelos totais.
+pub fn resultado_para_csv(resultado: &ResultadoCalculo) -> String {
+    let calendario = resultado.calendario.as_ref();
+    let opcional = |valor: Option<String>| valor.unwrap_or_default();
+
+    let valores = [
+        resultado.taxa_fixa.to_string(),
+        resultado.taxa_transporte.to_string(),
+        resultado.dias_trabalhados_fracionarios.to_string(),
+        resultado.viagens_por_dia.to_string(),
+        resultado.custo_transporte.to_string(),
+        resultado.proventos_total.to_string(),
+        resultado.deducoes_total.to_string(),
+        resultado.pagamento_final.to_string(),
+        opcional(calendario.and_then(|c| c.mes).map(|mes| mes.to_string())),
+        opcional(calendario.and_then(|c| c.ano).map(|ano| ano.to_string())),
+        opcional(calendario.and_then(|c| c.nome_mes).map(campo_csv)),
+        opcional(calendario.map(|c| c.dias_uteis_mes.to_string())),
+        opcional(calendario.map(|c| c.feriados_deduzidos.to_string())),
+        opcional(calendario.map(|c| c.dias_trabalhados.to_string())),
+    ];
+
+    format!("{}\n{}\n", CABECALHO_CSV.join(","), valores.join(","))
+}
+
+// Aspas só quando o texto tem separador, aspas ou quebra de linha
+fn campo_csv(texto: &str) -> String {
+    if texto.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", texto.replace('"', "\"\""))
+    } else {
+        texto.to_string()
+    }
+}
+
+/// Todos os campos do resultado como um objeto JSON. Valores monetários
+/// saem como números com duas casas (1234.50) e o calendário ausente sai
+/// como `null`. É um formato de saída para quem lê em reais; com a
+/// feature `serde`, `Money` é serializado como inteiro de centavos
+/// (123450) para o round-trip ser exato, então os dois JSONs diferem
+/// nos valores monetários.
+pub fn resultado_para_json(resultado: &ResultadoCalculo) -> String {
+    let itens = |itens: &mut dyn Iterator<Item = (&str, String)>| {
+        let itens: Vec<String> = itens
+            .map(|(descricao, valor)| {
+                format!(
+                    "{{\"descricao\":{},\"valor\":{}}}",
+                    texto_json(descricao),
+                    valor
+                )
+            })
+            .collect();
+        format!("[{}]", itens.join(","))
+    };
+
+    let mut saida = String::from("{");
+    write!(saida, "\"taxa_fixa\":{}", resultado.taxa_fixa).unwrap();
+    write!(
+        saida,
+        ",\"componentes_fixos\":{}",
+        itens(
+            &mut resultado
+                .componentes_fixos
+                .iter()
+                .map(|(descricao, valor)| (descricao.as_str(), valor.to_string()))
+        )
+    )
+    .unwrap();
+    write!(saida, ",\"taxa_transporte\":{}", resultado.taxa_transporte).unwrap();
+    write!(
+        saida,
+        ",\"dias_trabalhados\":{}",
+        resultado.dias_trabalhados
+    )
+    .unwrap();
+    write!(
+        saida,
+        ",\"dias_trabalhados_fracionarios\":{}",
+        resultado.dias_trabalhados_fracionarios
+    )
+    .unwrap();
+    write!(saida, ",\"viagens_por_dia\":{}", resultado.viagens_por_dia).unwrap();
+    write!(
+        saida,
+        ",\"custo_transporte\":{}",
+        resultado.custo_transporte
+    )
+    .unwrap();
+    write!(
+        saida,
+        ",\"proventos\":{}",
+        itens(
+            &mut resultado
+                .proventos
+                .iter()
+                .map(|p| (p.descricao.as_str(), p.valor.to_string()))
+        )
+    )
+    .unwrap();
+    write!(saida, ",\"proventos_total\":{}", resultado.proventos_total).unwrap();
+    write!(
+        saida,
+        ",\"deducoes\":{}",
+        itens(
+            &mut resultado
+                .deducoes
+                .iter()
+                .map(|d| (d.descricao.as_str(), d.valor.to_string()))
+        )
+    )
+    .unwrap();
+    write!(saida, ",\"deducoes_total\":{}", resultado.deducoes_total).unwrap();
+    write!(saida, ",\"pagamento_final\":{}", resultado.pagamento_final).unwrap();
+    match &resultado.calendario {
+        Some(calendario) => write!(saida, ",\"calendario\":{}", calendario_json(calendario)),
+        None => write!(saida, ",\"calendario\":null"),
+    }
+    .unwrap();
+    saida.push('}');
+
+    saida
+}
+
+fn calendario_json(calendario: &InformacaoCalendario) -> String {
+    let opcional = |valor: Option<String>| valor.unwrap_or_else(|| "null".to_string());
+    let feriados: Vec<String> = calendario
+        .feriados
+        .iter()
+        .map(|feriado| {
+            format!(
+                "{{\"data\":\"{}\",\"nome\":{},\"deduzido\":{}}}",
+                feriado.data.format("%Y-%m-%d"),
+                opcional(feriado.nome.as_deref().map(texto_json)),
+                feriado.deduzido
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\"mes\":{},\"ano\":{},\"nome_mes\":{},\"dias_uteis_mes\":{},\
+         \"feriados_deduzidos\":{},\"feriados_informados\":{},\"dias_trabalhados\":{},\
+         \"feriados\":[{}]}}",
+        opcional(calendario.mes.map(|mes| mes.to_string())),
+        opcional(calendario.ano.map(|ano| ano.to_string())),
+        opcional(calendario.nome_mes.map(texto_json)),
+        calendario.dias_uteis_mes,
+        calendario.feriados_deduzidos,
+        calendario.feriados_informados,
+        calendario.dias_trabalhados,
+        feriados.join(",")
+    )
+}
+
+fn texto_json(texto: &str) -> String {
+    let mut saida = String::from("\"");
+    for c in texto.chars() {
+        match c {
+            '"' => saida.push_str("\\\""),
+            '\\' => saida.push_str("\\\\"),
+            '\n' => saida.push_str("\\n"),
+            '\r' => saida.push_str("\\r"),
+            '\t' => saida.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(saida, "\\u{:04x}", c as u32).unwrap(),
+            c => saida.push(c),
+        }
+    }
+    saida.push('"');
+    saida
+}
+
+/// Chaves de `ResultadoCalculo::para_contexto`. Estão sempre todas
+/// presentes; as do calendário ficam vazias quando não há calendário.
+pub const CHAVES_CONTEXTO: [&str; 13] = [
+    "taxa_fixa",
+    "taxa_transporte",
+    "dias_trabalhados",
+    "viagens_por_dia",
+    "custo_transporte",
+    "proventos_total",
+    "deducoes_total",
+    "pagamento_final",
+    "mes",
+    "ano",
+    "nome_mes",
+    "dias_uteis_mes",
+    "feriados_deduzidos",
+];
+
+impl ResultadoCalculo {
+    /// Campos já formatados para preencher um template (Handlebars ou
+    /// outro motor), com as chaves de `CHAVES_CONTEXTO`. Valores
+    /// monetários vêm no padrão brasileiro ("R$ 1.234,56") e dias
+    /// fracionários com vírgula ("18,5").
+    pub fn para_contexto(&self) -> HashMap<String, String> {
+        let calendario = self.calendario.as_ref();
+        let opcional = |valor: Option<String>| valor.unwrap_or_default();
+
+        let valores = [
+            self.taxa_fixa.format_brl_com_simbolo(),
+            self.taxa_transporte.format_brl_com_simbolo(),
+            self.dias_trabalhados_fracionarios
+                .to_string()
+                .replace('.', ","),
+            self.viagens_por_dia.to_string(),
+            self.custo_transporte.format_brl_com_simbolo(),
+            self.proventos_total.format_brl_com_simbolo(),
+            self.deducoes_total.format_brl_com_simbolo(),
+            self.pagamento_final.format_brl_com_simbolo(),
+            opcional(calendario.and_then(|c| c.mes).map(|mes| mes.to_string())),
+            opcional(calendario.and_then(|c| c.ano).map(|ano| ano.to_string())),
+            opcional(calendario.and_then(|c| c.nome_mes).map(str::to_string)),
+            opcional(calendario.map(|c| c.dias_uteis_mes.to_string())),
+            opcional(calendario.map(|c| c.feriados_deduzidos.to_string())),
+        ];
+
+        CHAVES_CONTEXTO
+            .iter()
+            .map(|chave| chave.to_string())
+            .zip(valores)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        calcular_valores, calcular_valores_com_calendario, calcular_valores_com_feriados_nacionais,
+        Deducao, Money,
+    };
+    use serde_json::Value;
+
+    fn com_calendario() -> ResultadoCalculo {
+        calcular_valores_com_calendario(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            11,
+            2024,
+            2,
+            vec![Deducao {
+                valor: Money::parse("25.05").unwrap(),
+                descricao: "vale \"refeição\"".to_string(),
+            }],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_csv_com_calendario() {
+        let csv = resultado_para_csv(&com_calendario());
+        let linhas: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(linhas.len(), 2);
+        assert_eq!(linhas[0], CABECALHO_CSV.join(","));
+        assert_eq!(
+            linhas[1],
+            "150.00,7.50,19,2,285.00,0.00,25.05,409.95,11,2024,Novembro,21,2,19"
+        );
+    }
+
+    #[test]
+    fn test_csv_sem_calendario_deixa_colunas_vazias() {
+        let resultado = calcular_valores(Money::from_reais(100), Money::from_reais(5), 10, vec![]);
+
+        let csv = resultado_para_csv(&resultado);
+        let valores: Vec<&str> = csv.lines().nth(1).unwrap().split(',').collect();
+
+        assert_eq!(valores.len(), CABECALHO_CSV.len());
+        assert_eq!(valores[7], "200.00");
+        assert!(valores[8..].iter().all(|v| v.is_empty()));
+    }
+
+    #[test]
+    fn test_json_com_calendario() {
+        let json: Value = serde_json::from_str(&resultado_para_json(&com_calendario())).unwrap();
+
+        assert_eq!(json["taxa_fixa"], 150.0);
+        assert_eq!(json["pagamento_final"], 409.95);
+        assert_eq!(json["deducoes"][0]["descricao"], "vale \"refeição\"");
+        assert_eq!(json["deducoes"][0]["valor"], 25.05);
+        assert_eq!(json["calendario"]["nome_mes"], "Novembro");
+        assert_eq!(json["calendario"]["dias_uteis_mes"], 21);
+        assert_eq!(json["calendario"]["feriados_informados"], 2);
+        assert_eq!(json["calendario"]["feriados"], Value::Array(vec![]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_em_reais_e_serde_em_centavos() {
+        let resultado = com_calendario();
+
+        let manual: Value = serde_json::from_str(&resultado_para_json(&resultado)).unwrap();
+        let serde: Value = serde_json::to_value(&resultado).unwrap();
+
+        assert_eq!(manual["pagamento_final"], 409.95);
+        assert_eq!(serde["pagamento_final"], 40995);
+        assert_eq!(manual["deducoes"][0]["valor"], 25.05);
+        assert_eq!(serde["deducoes"][0]["valor"], 2505);
+        assert_eq!(manual["dias_trabalhados"], serde["dias_trabalhados"]);
+    }
+
+    #[test]
+    fn test_json_sem_calendario() {
+        let resultado = calcular_valores(Money::ZERO, Money::ZERO, 0, vec![]);
+
+        let json: Value = serde_json::from_str(&resultado_para_json(&resultado)).unwrap();
+
+        assert_eq!(json["calendario"], Value::Null);
+        assert_eq!(json["deducoes"], Value::Array(vec![]));
+        assert_eq!(json["pagamento_final"], 0.0);
+    }
+
+    #[test]
+    fn test_json_lista_feriados() {
+        let resultado =
+            calcular_valores_com_feriados_nacionais(Money::ZERO, Money::ZERO, 11, 2024, vec![])
+                .unwrap();
+
+        let json: Value = serde_json::from_str(&resultado_para_json(&resultado)).unwrap();
+
+        let feriado = &json["calendario"]["feriados"][0];
+        assert_eq!(feriado["data"], "2024-11-02");
+        assert_eq!(feriado["nome"], "Finados");
+        assert_eq!(feriado["deduzido"], false);
+    }
+
+    #[test]
+    fn test_para_contexto_chaves() {
+        let contexto = com_calendario().para_contexto();
+
+        let mut chaves: Vec<&str> = contexto.keys().map(String::as_str).collect();
+        chaves.sort();
+        let mut esperadas = CHAVES_CONTEXTO.to_vec();
+        esperadas.sort();
+        assert_eq!(chaves, esperadas);
+
+        assert_eq!(contexto["pagamento_final"], "R$ 409,95");
+        assert_eq!(contexto["nome_mes"], "Novembro");
+        assert_eq!(contexto["dias_trabalhados"], "19");
+    }
+
+    #[test]
+    fn test_para_contexto_sem_calendario() {
+        let resultado = crate::calcular_valores_fracionarios(
+            Money::from_reais(1500),
+            Money::from_reais(5),
+            18.5,
+            vec![],
+        );
+
+        let contexto = resultado.para_contexto();
+
+        assert_eq!(contexto.len(), CHAVES_CONTEXTO.len());
+        assert_eq!(contexto["nome_mes"], "");
+        assert_eq!(contexto["mes"], "");
+        assert_eq!(contexto["dias_trabalhados"], "18,5");
+        assert_eq!(contexto["taxa_fixa"], "R$ 1.500,00");
+    }
+}
@@ -0,0 +1,380 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegraFeriado {
+    // n-ésima ocorrência do dia da semana no mês (1 = primeira)
+    NesimoDiaSemana(u8, Weekday),
+    // última ocorrência do dia da semana no mês
+    UltimoDiaSemana(Weekday),
+}
+
+impl RegraFeriado {
+    /// Data gerada pela regra no mês/ano informado, ou `None` quando o mês
+    /// não tem a ocorrência pedida (ex: 5ª sexta de um mês com 4 sextas).
+    pub fn data(&self, mes: u32, ano: i32) -> Option<NaiveDate> {
+        match *self {
+            RegraFeriado::NesimoDiaSemana(n, dia_semana) => {
+                NaiveDate::from_weekday_of_month_opt(ano, mes, dia_semana, n)
+            }
+            RegraFeriado::UltimoDiaSemana(dia_semana) => {
+                let mut data = ultimo_dia_do_mes(mes, ano)?;
+                while data.weekday() != dia_semana {
+                    data = data.pred_opt()?;
+                }
+                Some(data)
+            }
+        }
+    }
+
+    pub fn datas_no_ano(&self, ano: i32) -> Vec<NaiveDate> {
+        (1..=12).filter_map(|mes| self.data(mes, ano)).collect()
+    }
+}
+
+// (UF, mês, dia) dos feriados estaduais de data fixa
+const FERIADOS_ESTADUAIS: [(&str, u32, u32); 10] = [
+    ("AM", 9, 5),   // Elevação do Amazonas à categoria de província
+    ("BA", 7, 2),   // Independência da Bahia
+    ("CE", 3, 25),  // Data Magna do Ceará
+    ("DF", 11, 30), // Dia do Evangélico
+    ("PA", 8, 15),  // Adesão do Pará à independência
+    ("PE", 3, 6),   // Revolução Pernambucana
+    ("RJ", 4, 23),  // Dia de São Jorge
+    ("RS", 9, 20),  // Revolução Farroupilha
+    ("SP", 7, 9),   // Revolução Constitucionalista
+    ("TO", 10, 5),  // Criação do estado
+];
+
+fn uf_coberta(uf: &str) -> bool {
+    FERIADOS_ESTADUAIS
+        .iter()
+        .any(|(sigla, _, _)| sigla.eq_ignore_ascii_case(uf.trim()))
+}
+
+/// Feriados estaduais de data fixa da UF no ano, em ordem cronológica,
+/// para combinar com os nacionais. A tabela é mínima: UFs não cobertas
+/// retornam lista vazia, e `aviso_feriados_estaduais` diz quando é o caso.
+pub fn feriados_estaduais(uf: &str, ano: i32) -> Vec<NaiveDate> {
+    let mut datas: Vec<NaiveDate> = FERIADOS_ESTADUAIS
+        .iter()
+        .filter(|(sigla, _, _)| sigla.eq_ignore_ascii_case(uf.trim()))
+        .filter_map(|&(_, mes, dia)| NaiveDate::from_ymd_opt(ano, mes, dia))
+        .collect();
+
+    datas.sort();
+    datas
+}
+
+/// Aviso para quem chama mostrar quando a UF não tem feriados estaduais
+/// na tabela e `feriados_estaduais` volta vazia; `None` para UFs cobertas.
+pub fn aviso_feriados_estaduais(uf: &str) -> Option<String> {
+    if uf_coberta(uf) {
+        None
+    } else {
+        Some(format!(
+            "Aviso: sem feriados estaduais cadastrados para a UF {}",
+            uf.trim()
+        ))
+    }
+}
+
+pub(crate) fn ultimo_dia_do_mes(mes: u32, ano: i32) -> Option<NaiveDate> {
+    let proximo_mes = if mes == 12 { 1 } else { mes + 1 };
+    let proximo_ano = if mes == 12 { ano + 1 } else { ano };
+
+    NaiveDate::from_ymd_opt(proximo_ano, proximo_mes, 1)?.pred_opt()
+}
+
+/// Domingo de Páscoa pelo algoritmo de Gauss (computus gregoriano).
+pub fn pascoa(ano: i32) -> Option<NaiveDate> {
+    let a = ano % 19;
+    let b = ano / 100;
+    let c = ano % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let mes = (h + l - 7 * m + 114) / 31;
+    let dia = (h + l - 7 * m + 114) % 31 + 1;
+
+    NaiveDate::from_ymd_opt(ano, mes as u32, dia as u32)
+}
+
+// (mês, dia, nome) dos feriados nacionais de data fixa
+const FERIADOS_NACIONAIS_FIXOS: [(u32, u32, &str); 8] = [
+    (1, 1, "Confraternização Universal"),
+    (4, 21, "Tiradentes"),
+    (5, 1, "Dia do Trabalho"),
+    (9, 7, "Independência do Brasil"),
+    (10, 12, "Nossa Senhora Aparecida"),
+    (11, 2, "Finados"),
+    (11, 15, "Proclamação da República"),
+    (12, 25, "Natal"),
+];
+
+// (dias a partir da Páscoa, nome) dos feriados móveis
+const FERIADOS_NACIONAIS_MOVEIS: [(i64, &str); 4] = [
+    (-48, "Carnaval"),
+    (-47, "Carnaval"),
+    (-2, "Sexta-feira Santa"),
+    (60, "Corpus Christi"),
+];
+
+fn feriados_nacionais_com_nome(ano: i32) -> Vec<(NaiveDate, &'static str)> {
+    let mut feriados: Vec<(NaiveDate, &'static str)> = FERIADOS_NACIONAIS_FIXOS
+        .iter()
+        .filter_map(|&(mes, dia, nome)| Some((NaiveDate::from_ymd_opt(ano, mes, dia)?, nome)))
+        .collect();
+
+    if ano >= 2024 {
+        feriados
+            .extend(NaiveDate::from_ymd_opt(ano, 11, 20).map(|data| (data, "Consciência Negra")));
+    }
+
+    if let Some(pascoa) = pascoa(ano) {
+        feriados.extend(
+            FERIADOS_NACIONAIS_MOVEIS
+                .iter()
+                .filter_map(|&(dias, nome)| {
+                    Some((pascoa.checked_add_signed(Duration::days(dias))?, nome))
+                }),
+        );
+    }
+
+    feriados.sort();
+    feriados
+}
+
+/// Feriados nacionais do ano em ordem cronológica, incluindo fins de
+/// semana: os fixos, a Consciência Negra (a partir de 2024) e os móveis
+/// da Páscoa (segunda e terça de Carnaval, Sexta-feira Santa e Corpus
+/// Christi).
+pub fn feriados_nacionais(ano: i32) -> Vec<NaiveDate> {
+    feriados_nacionais_com_nome(ano)
+        .into_iter()
+        .map(|(data, _)| data)
+        .collect()
+}
+
+/// Nome do feriado nacional que cai na data, se houver.
+pub fn nome_feriado(data: NaiveDate) -> Option<&'static str> {
+    feriados_nacionais_com_nome(data.year())
+        .into_iter()
+        .find(|&(feriado, _)| feriado == data)
+        .map(|(_, nome)| nome)
+}
+
+// Política para o dia útil espremido entre um feriado e o fim de semana
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PoliticaEmenda {
+    #[default]
+    SemEmenda,
+    // Feriado na terça emenda a segunda; na quinta, a sexta
+    Enforcado,
+}
+
+impl PoliticaEmenda {
+    /// Dias-ponte gerados pelos feriados, em ordem e sem repetir datas que
+    /// já são feriado.
+    pub fn dias_ponte(&self, feriados: &[NaiveDate]) -> Vec<NaiveDate> {
+        if *self == PoliticaEmenda::SemEmenda {
+            return Vec::new();
+        }
+
+        let mut pontes: Vec<NaiveDate> = feriados
+            .iter()
+            .filter_map(|feriado| match feriado.weekday() {
+                Weekday::Tue => feriado.pred_opt(),
+                Weekday::Thu => feriado.succ_opt(),
+                _ => None,
+            })
+            .filter(|ponte| !feriados.contains(ponte))
+            .collect();
+        pontes.sort();
+        pontes.dedup();
+        pontes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(ano: i32, mes: u32, dia: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(ano, mes, dia).unwrap()
+    }
+
+    #[test]
+    fn test_ultima_sexta_do_mes() {
+        let regra = RegraFeriado::UltimoDiaSemana(Weekday::Fri);
+
+        // Novembro 2024 tem 5 sextas (1, 8, 15, 22, 29)
+        assert_eq!(regra.data(11, 2024), Some(data(2024, 11, 29)));
+        // Fevereiro 2024 tem 4 sextas (2, 9, 16, 23)
+        assert_eq!(regra.data(2, 2024), Some(data(2024, 2, 23)));
+        // Maio 2025 termina numa sexta
+        assert_eq!(regra.data(5, 2025), Some(data(2025, 5, 30)));
+        // Dezembro cruza a virada de ano no cálculo do último dia
+        assert_eq!(regra.data(12, 2024), Some(data(2024, 12, 27)));
+    }
+
+    #[test]
+    fn test_primeira_sexta_do_mes() {
+        let regra = RegraFeriado::NesimoDiaSemana(1, Weekday::Fri);
+
+        assert_eq!(regra.data(11, 2024), Some(data(2024, 11, 1)));
+        assert_eq!(regra.data(2, 2024), Some(data(2024, 2, 2)));
+        assert_eq!(regra.data(3, 2024), Some(data(2024, 3, 1)));
+        assert_eq!(regra.data(6, 2024), Some(data(2024, 6, 7)));
+    }
+
+    #[test]
+    fn test_quinta_ocorrencia_inexistente() {
+        let regra = RegraFeriado::NesimoDiaSemana(5, Weekday::Fri);
+
+        assert_eq!(regra.data(11, 2024), Some(data(2024, 11, 29)));
+        assert_eq!(regra.data(2, 2024), None);
+    }
+
+    #[test]
+    fn test_mes_invalido() {
+        assert_eq!(
+            RegraFeriado::UltimoDiaSemana(Weekday::Fri).data(13, 2024),
+            None
+        );
+        assert_eq!(
+            RegraFeriado::NesimoDiaSemana(1, Weekday::Fri).data(0, 2024),
+            None
+        );
+    }
+
+    #[test]
+    fn test_datas_no_ano() {
+        let datas = RegraFeriado::UltimoDiaSemana(Weekday::Fri).datas_no_ano(2024);
+
+        assert_eq!(datas.len(), 12);
+        assert!(datas.iter().all(|d| d.weekday() == Weekday::Fri));
+        assert_eq!(datas[0], data(2024, 1, 26));
+        assert_eq!(datas[11], data(2024, 12, 27));
+    }
+
+    #[test]
+    fn test_pascoa_anos_conhecidos() {
+        assert_eq!(pascoa(1900), Some(data(1900, 4, 15)));
+        assert_eq!(pascoa(1943), Some(data(1943, 4, 25))); // a mais tardia possível
+        assert_eq!(pascoa(2008), Some(data(2008, 3, 23)));
+        assert_eq!(pascoa(2024), Some(data(2024, 3, 31)));
+        assert_eq!(pascoa(2025), Some(data(2025, 4, 20)));
+        assert_eq!(pascoa(2100), Some(data(2100, 3, 28)));
+    }
+
+    #[test]
+    fn test_pascoa_sempre_domingo_entre_22_marco_e_25_abril() {
+        for ano in 1900..=2100 {
+            let data_pascoa = pascoa(ano).unwrap();
+            assert_eq!(data_pascoa.weekday(), Weekday::Sun, "{}", ano);
+            assert!(data_pascoa >= data(ano, 3, 22) && data_pascoa <= data(ano, 4, 25));
+        }
+    }
+
+    #[test]
+    fn test_feriados_nacionais_2024() {
+        let feriados = feriados_nacionais(2024);
+
+        assert_eq!(feriados.len(), 13);
+        assert!(feriados.windows(2).all(|par| par[0] < par[1]));
+        assert!(feriados.contains(&data(2024, 2, 12))); // segunda de Carnaval
+        assert!(feriados.contains(&data(2024, 2, 13))); // terça de Carnaval
+        assert!(feriados.contains(&data(2024, 3, 29))); // Sexta-feira Santa
+        assert!(feriados.contains(&data(2024, 5, 30))); // Corpus Christi
+        assert!(feriados.contains(&data(2024, 11, 20)));
+        assert!(!feriados_nacionais(2023).contains(&data(2023, 11, 20)));
+    }
+
+    #[test]
+    fn test_nome_feriado() {
+        assert_eq!(nome_feriado(data(2024, 12, 25)), Some("Natal"));
+        assert_eq!(nome_feriado(data(2024, 4, 21)), Some("Tiradentes"));
+        assert_eq!(nome_feriado(data(2024, 2, 13)), Some("Carnaval"));
+        assert_eq!(nome_feriado(data(2024, 3, 29)), Some("Sexta-feira Santa"));
+        assert_eq!(nome_feriado(data(2025, 6, 19)), Some("Corpus Christi"));
+        assert_eq!(nome_feriado(data(2024, 11, 20)), Some("Consciência Negra"));
+    }
+
+    #[test]
+    fn test_nome_feriado_dia_comum() {
+        assert_eq!(nome_feriado(data(2024, 12, 24)), None);
+        assert_eq!(nome_feriado(data(2024, 3, 28)), None);
+        assert_eq!(nome_feriado(data(2023, 11, 20)), None);
+        // Feriado estadual não é nacional
+        assert_eq!(nome_feriado(data(2024, 7, 9)), None);
+    }
+
+    #[test]
+    fn test_enforcado_terca_emenda_segunda() {
+        // 12/11/2024 é terça
+        let pontes = PoliticaEmenda::Enforcado.dias_ponte(&[data(2024, 11, 12)]);
+
+        assert_eq!(pontes, vec![data(2024, 11, 11)]);
+        assert_eq!(pontes[0].weekday(), Weekday::Mon);
+    }
+
+    #[test]
+    fn test_enforcado_quinta_emenda_sexta() {
+        // Corpus Christi de 2025 cai numa quinta
+        let pontes = PoliticaEmenda::Enforcado.dias_ponte(&[data(2025, 6, 19)]);
+
+        assert_eq!(pontes, vec![data(2025, 6, 20)]);
+        assert_eq!(pontes[0].weekday(), Weekday::Fri);
+    }
+
+    #[test]
+    fn test_enforcado_sem_ponte() {
+        // Segunda, quarta, sexta e sábado não geram ponte
+        let feriados = [
+            data(2024, 11, 4),
+            data(2024, 11, 20),
+            data(2024, 11, 15),
+            data(2024, 11, 2),
+        ];
+
+        assert!(PoliticaEmenda::Enforcado.dias_ponte(&feriados).is_empty());
+        assert!(PoliticaEmenda::SemEmenda
+            .dias_ponte(&[data(2024, 11, 12)])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_enforcado_ponte_que_ja_e_feriado() {
+        // Carnaval de 2024: segunda (12/02) e terça (13/02) já são feriados
+        let feriados = feriados_nacionais(2024);
+
+        let pontes = PoliticaEmenda::Enforcado.dias_ponte(&feriados);
+
+        assert!(!pontes.contains(&data(2024, 2, 12)));
+        // Corpus Christi 30/05/2024 (quinta) emenda a sexta
+        assert!(pontes.contains(&data(2024, 5, 31)));
+    }
+
+    #[test]
+    fn test_feriado_estadual_sao_paulo() {
+        assert_eq!(feriados_estaduais("SP", 2024), vec![data(2024, 7, 9)]);
+        assert_eq!(feriados_estaduais("sp", 2025), vec![data(2025, 7, 9)]);
+    }
+
+    #[test]
+    fn test_feriado_estadual_uf_nao_coberta() {
+        assert!(feriados_estaduais("MG", 2024).is_empty());
+        assert!(feriados_estaduais("", 2024).is_empty());
+        assert_eq!(
+            aviso_feriados_estaduais(" MG "),
+            Some("Aviso: sem feriados estaduais cadastrados para a UF MG".to_string())
+        );
+        assert_eq!(aviso_feriados_estaduais("sp"), None);
+    }
+}
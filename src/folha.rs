@@ -0,0 +1,634 @@
+use crate::analise::dividir_arredondado;
+use crate::{Deducao, ErroCalculo, Money};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FaixaINSS {
+    pub limite: Money,             // teto da faixa, inclusive
+    pub aliquota_pontos_base: u32, // 750 = 7,5%
+}
+
+// Faixas em ordem crescente de limite; o limite da última é o teto
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TabelaINSS {
+    pub faixas: Vec<FaixaINSS>,
+}
+
+impl TabelaINSS {
+    pub fn tabela_2024() -> Self {
+        let faixa = |limite: i64, aliquota_pontos_base| FaixaINSS {
+            limite: Money::from_centavos(limite),
+            aliquota_pontos_base,
+        };
+
+        TabelaINSS {
+            faixas: vec![
+                faixa(141200, 750),
+                faixa(266668, 900),
+                faixa(400003, 1200),
+                faixa(778602, 1400),
+            ],
+        }
+    }
+}
+
+/// Contribuição progressiva: cada alíquota incide apenas sobre a parcela
+/// da base dentro da sua faixa, e o que passa do teto não contribui. A
+/// contribuição de cada faixa é truncada ao centavo antes da soma, como
+/// na tabela oficial (teto de 2024: 105,90 + 112,92 + 160,00 + 530,03).
+pub fn calcular_inss(base: Money, tabela: &TabelaINSS) -> Money {
+    let base = base.to_centavos().max(0) as i128;
+    let mut limite_anterior = 0i128;
+    let mut total = 0i128;
+
+    for faixa in &tabela.faixas {
+        let limite = faixa.limite.to_centavos() as i128;
+        let parcela = base.min(limite) - limite_anterior;
+        if parcela <= 0 {
+            break;
+        }
+
+        // centavos × 10.000, truncado ao centavo
+        let contribuicao = parcela * faixa.aliquota_pontos_base as i128;
+        total += contribuicao / 10_000;
+        limite_anterior = limite;
+    }
+
+    Money::from_centavos(total as i64)
+}
+
+/// Aplica o fator de correção e arredonda ao centavo, em aritmética
+/// inteira (veja `Money::mul_fracao`).
+pub fn corrigir_valor(valor: Money, fator_correcao: f64) -> Money {
+    valor.mul_fracao(fator_correcao)
+}
+
+/// Soma valores em moedas diferentes, cada um com o fator de câmbio para
+/// real (1.0 para o que já está em real). Cada valor é convertido ao
+/// centavo com `Money::mul_fracao` e os valores convertidos são somados.
+pub fn somar_convertendo(valores: &[(Money, f64)]) -> Money {
+    valores
+        .iter()
+        .map(|&(valor, fator)| valor.mul_fracao(fator))
+        .sum()
+}
+
+/// Corrige uma série de competências até o fim do período. Cada item traz
+/// o valor da competência e o fator de variação do índice naquele mês; o
+/// valor é multiplicado pelo fator acumulado do seu mês até o último, e
+/// só então arredondado ao centavo.
+pub fn corrigir_competencias(competencias: &[(Money, f64)]) -> Vec<Money> {
+    let mut acumulado = 1.0;
+    let mut corrigidos: Vec<Money> = competencias
+        .iter()
+        .rev()
+        .map(|&(valor, fator)| {
+            acumulado *= fator;
+            corrigir_valor(valor, acumulado)
+        })
+        .collect();
+
+    corrigidos.reverse();
+    corrigidos
+}
+
+// Percentual do salário usual como teto de descontos
+pub const LIMITE_DESCONTOS_PADRAO: f64 = 30.0;
+
+/// Confere que os descontos não passam de `limite_percentual` do salário
+/// (30.0 = 30%). O valor permitido é arredondado ao centavo e atingi-lo
+/// exatamente ainda é válido.
+pub fn validar_limite_descontos(
+    salario: Money,
+    deducoes: Money,
+    limite_percentual: f64,
+) -> Result<(), ErroCalculo> {
+    let permitido = salario.mul_fracao(limite_percentual / 100.0);
+
+    if deducoes > permitido {
+        return Err(ErroCalculo::DescontosAcimaDoLimite {
+            permitido,
+            solicitado: deducoes,
+        });
+    }
+
+    Ok(())
+}
+
+/// Bônus pelos dias trabalhados além da meta; zero na meta ou abaixo dela.
+pub fn bonus_por_meta(
+    dias_trabalhados: i32,
+    meta_dias: i32,
+    bonus_por_dia_excedente: Money,
+) -> Money {
+    bonus_por_dia_excedente * (dias_trabalhados - meta_dias).max(0)
+}
+
+/// Valor de um benefício por tempo de casa (anuênio, adicional por tempo
+/// de serviço). Cada faixa é `(min_meses, valor)` e vale a partir de
+/// `min_meses` completos, inclusive; aplica-se a faixa de maior
+/// `min_meses` já alcançada, independente da ordem da lista. Sem faixa
+/// alcançada o valor é zero.
+pub fn valor_por_tempo_de_casa(faixas: &[(u32, Money)], meses_de_casa: u32) -> Money {
+    faixas
+        .iter()
+        .filter(|(min_meses, _)| *min_meses <= meses_de_casa)
+        .max_by_key(|(min_meses, _)| *min_meses)
+        .map_or(Money::ZERO, |&(_, valor)| valor)
+}
+
+// Quantos dias o salário é dividido para chegar ao valor-dia
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DivisorDias {
+    DiasUteis,
+    #[default]
+    Comercial30, // mês comercial
+    Personalizado(i32),
+}
+
+impl DivisorDias {
+    pub fn dias(&self, dias_uteis_mes: i32) -> i32 {
+        match *self {
+            DivisorDias::DiasUteis => dias_uteis_mes,
+            DivisorDias::Comercial30 => 30,
+            DivisorDias::Personalizado(dias) => dias,
+        }
+    }
+}
+
+/// Valor de um dia de trabalho, arredondado ao centavo. `dias_uteis_mes`
+/// só é usado com `DivisorDias::DiasUteis`.
+pub fn valor_dia(
+    salario: Money,
+    divisor: DivisorDias,
+    dias_uteis_mes: i32,
+) -> Result<Money, ErroCalculo> {
+    let dias = divisor.dias(dias_uteis_mes);
+    if dias <= 0 {
+        return Err(ErroCalculo::DivisorInvalido(dias));
+    }
+
+    Ok(Money::from_centavos(dividir_arredondado(
+        salario.to_centavos(),
+        dias as i64,
+    )))
+}
+
+/// Desconto proporcional às faltas: valor-dia multiplicado pelas faltas.
+pub fn desconto_faltas(
+    salario: Money,
+    faltas: i32,
+    divisor: DivisorDias,
+    dias_uteis_mes: i32,
+) -> Result<Deducao, ErroCalculo> {
+    Ok(Deducao {
+        valor: valor_dia(salario, divisor, dias_uteis_mes)? * faltas,
+        descricao: "Faltas".to_string(),
+    })
+}
+
+// Empréstimo consignado com parcela fixa descontada mês a mês
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Consignado {
+    pub parcela: Money,
+    pub parcelas_restantes: u32,
+}
+
+impl Consignado {
+    pub fn saldo_devedor(&self) -> Money {
+        Money::from_centavos(self.parcela.to_centavos() * self.parcelas_restantes as i64)
+    }
+
+    /// Parcela do mês como dedução, decrementando as parcelas restantes.
+    /// Depois de quitado não há mais desconto.
+    pub fn descontar_parcela(&mut self) -> Option<Deducao> {
+        if self.parcelas_restantes == 0 {
+            return None;
+        }
+
+        self.parcelas_restantes -= 1;
+        Some(Deducao {
+            valor: self.parcela,
+            descricao: "Consignado".to_string(),
+        })
+    }
+}
+
+/// Competência da última parcela, contando que a de `mes_atual` já foi
+/// descontada e restam `parcelas_restantes` nos meses seguintes. Sem
+/// parcelas restantes, a quitação é a própria competência atual.
+pub fn data_quitacao(parcelas_restantes: u32, mes_atual: u32, ano_atual: i32) -> (u32, i32) {
+    let meses = ano_atual as i64 * 12 + (mes_atual as i64 - 1) + parcelas_restantes as i64;
+
+    ((meses % 12) as u32 + 1, (meses / 12) as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calcular_valores, Provento};
+
+    fn inss(base: &str) -> Money {
+        calcular_inss(Money::parse(base).unwrap(), &TabelaINSS::tabela_2024())
+    }
+
+    #[test]
+    fn test_inss_primeira_faixa() {
+        assert_eq!(inss("1000"), Money::parse("75.00").unwrap());
+        assert_eq!(inss("1412"), Money::parse("105.90").unwrap());
+    }
+
+    #[test]
+    fn test_inss_segunda_faixa() {
+        // 105.90 + (2000 - 1412) × 9% = 105.90 + 52.92
+        assert_eq!(inss("2000"), Money::parse("158.82").unwrap());
+    }
+
+    #[test]
+    fn test_inss_terceira_faixa() {
+        // 105.90 + 112.92 + (3000 - 2666.68) × 12% (39.9984 → 39.99)
+        assert_eq!(inss("3000"), Money::parse("258.81").unwrap());
+    }
+
+    #[test]
+    fn test_inss_quarta_faixa() {
+        // 105.90 + 112.92 + 160.00 + (5000 - 4000.03) × 14% (139.9958 → 139.99)
+        assert_eq!(inss("5000"), Money::parse("518.81").unwrap());
+    }
+
+    #[test]
+    fn test_inss_respeita_teto() {
+        // 105.90 + 112.92 + 160.00 + 530.03, cada faixa já arredondada
+        let no_teto = inss("7786.02");
+
+        assert_eq!(no_teto, Money::parse("908.85").unwrap());
+        assert_eq!(inss("10000"), no_teto);
+        assert_eq!(inss("50000"), no_teto);
+    }
+
+    #[test]
+    fn test_inss_aliquota_efetiva_menor_que_nominal() {
+        // 14% nominal sobre 5000 seria 700.00
+        assert!(inss("5000") < Money::parse("700.00").unwrap());
+    }
+
+    #[test]
+    fn test_inss_base_zero_ou_negativa() {
+        assert_eq!(inss("0"), Money::ZERO);
+        assert_eq!(inss("-100"), Money::ZERO);
+    }
+
+    #[test]
+    fn test_corrigir_valor() {
+        let valor = Money::from_reais(1000);
+
+        assert_eq!(
+            corrigir_valor(valor, 1.0453),
+            Money::parse("1045.30").unwrap()
+        );
+        assert_eq!(corrigir_valor(valor, 1.0), valor);
+        // 10.01 × 1.5 = 15.015 → 15.02
+        assert_eq!(
+            corrigir_valor(Money::parse("10.01").unwrap(), 1.5),
+            Money::parse("15.02").unwrap()
+        );
+        assert_eq!(
+            corrigir_valor(Money::parse("-20.50").unwrap(), 1.1),
+            Money::parse("-22.55").unwrap()
+        );
+        // 50 × 1.15 = 57.5 centavos; em f64 daria 57.4999... → 0.57
+        assert_eq!(
+            corrigir_valor(Money::parse("0.50").unwrap(), 1.15),
+            Money::parse("0.58").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_somar_convertendo_real_e_dolar() {
+        let valores = [
+            (Money::from_reais(3000), 1.0),
+            // US$ 500.00 a R$ 5.4321
+            (Money::from_reais(500), 5.4321),
+        ];
+
+        // 3000.00 + 2716.05
+        assert_eq!(
+            somar_convertendo(&valores),
+            Money::parse("5716.05").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_somar_convertendo_arredonda_cada_valor() {
+        // 0.01 × 0.4 e 0.01 × 0.6: cada conversão vai ao centavo antes da soma
+        let valores = [
+            (Money::from_centavos(1), 0.4),
+            (Money::from_centavos(1), 0.6),
+        ];
+
+        assert_eq!(somar_convertendo(&valores), Money::from_centavos(1));
+        // 0.50 × 1.15 = 0.575 → 0.58, sem o erro do ponto flutuante
+        assert_eq!(
+            somar_convertendo(&[(Money::parse("0.50").unwrap(), 1.15)]),
+            Money::parse("0.58").unwrap()
+        );
+        assert_eq!(
+            somar_convertendo(&[(Money::parse("-20.50").unwrap(), 1.0)]),
+            Money::parse("-20.50").unwrap()
+        );
+        assert_eq!(somar_convertendo(&[]), Money::ZERO);
+    }
+
+    #[test]
+    fn test_corrigir_competencias_fator_acumulado() {
+        let competencias = [
+            (Money::from_reais(100), 1.01),
+            (Money::from_reais(100), 1.02),
+            (Money::from_reais(100), 1.03),
+        ];
+
+        let corrigidos = corrigir_competencias(&competencias);
+
+        // 100 × 1.01 × 1.02 × 1.03 = 106.1106
+        assert_eq!(corrigidos[0], Money::parse("106.11").unwrap());
+        // 100 × 1.02 × 1.03 = 105.06
+        assert_eq!(corrigidos[1], Money::parse("105.06").unwrap());
+        assert_eq!(corrigidos[2], Money::parse("103.00").unwrap());
+    }
+
+    #[test]
+    fn test_corrigir_competencias_arredonda_uma_vez() {
+        // Arredondando mês a mês: 1 × 1.4 → 1 centavo, 1 × 1.4 → 1 centavo
+        let competencias = [
+            (Money::from_centavos(1), 1.4),
+            (Money::from_centavos(0), 1.4),
+            (Money::from_centavos(0), 1.0),
+        ];
+
+        // 0.01 × 1.4 × 1.4 = 0.0196 → 0.02
+        assert_eq!(
+            corrigir_competencias(&competencias)[0],
+            Money::from_centavos(2)
+        );
+        assert!(corrigir_competencias(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_limite_descontos_dentro() {
+        let salario = Money::from_reais(3000);
+
+        assert_eq!(
+            validar_limite_descontos(salario, Money::from_reais(500), LIMITE_DESCONTOS_PADRAO),
+            Ok(())
+        );
+        assert_eq!(
+            validar_limite_descontos(salario, Money::from_reais(900), LIMITE_DESCONTOS_PADRAO),
+            Ok(())
+        );
+        assert_eq!(
+            validar_limite_descontos(salario, Money::ZERO, LIMITE_DESCONTOS_PADRAO),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_limite_descontos_acima() {
+        let salario = Money::from_reais(3000);
+
+        assert_eq!(
+            validar_limite_descontos(
+                salario,
+                Money::parse("900.01").unwrap(),
+                LIMITE_DESCONTOS_PADRAO
+            ),
+            Err(ErroCalculo::DescontosAcimaDoLimite {
+                permitido: Money::from_reais(900),
+                solicitado: Money::parse("900.01").unwrap(),
+            })
+        );
+        // 35% de 1234.56 = 432.096 → 432.10
+        assert_eq!(
+            validar_limite_descontos(
+                Money::parse("1234.56").unwrap(),
+                Money::from_reais(500),
+                35.0
+            ),
+            Err(ErroCalculo::DescontosAcimaDoLimite {
+                permitido: Money::parse("432.10").unwrap(),
+                solicitado: Money::from_reais(500),
+            })
+        );
+        // 35% de 0.90 = 0.315 → 0.32
+        assert_eq!(
+            validar_limite_descontos(
+                Money::parse("0.90").unwrap(),
+                Money::parse("0.33").unwrap(),
+                35.0
+            ),
+            Err(ErroCalculo::DescontosAcimaDoLimite {
+                permitido: Money::parse("0.32").unwrap(),
+                solicitado: Money::parse("0.33").unwrap(),
+            })
+        );
+        assert_eq!(
+            validar_limite_descontos(
+                Money::parse("0.90").unwrap(),
+                Money::parse("0.32").unwrap(),
+                35.0
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_bonus_por_meta() {
+        let bonus = Money::parse("15.50").unwrap();
+
+        assert_eq!(bonus_por_meta(18, 20, bonus), Money::ZERO);
+        assert_eq!(bonus_por_meta(20, 20, bonus), Money::ZERO);
+        assert_eq!(
+            bonus_por_meta(23, 20, bonus),
+            Money::parse("46.50").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_bonus_por_meta_como_provento() {
+        let mut resultado = calcular_valores(Money::from_reais(1000), Money::ZERO, 22, vec![]);
+
+        resultado.adicionar_provento(Provento {
+            valor: bonus_por_meta(resultado.dias_trabalhados, 20, Money::from_reais(25)),
+            descricao: "Bônus por meta".to_string(),
+        });
+
+        assert_eq!(resultado.proventos_total, Money::from_reais(50));
+        assert_eq!(resultado.pagamento_final, Money::from_reais(1050));
+    }
+
+    fn faixas_anuenio() -> Vec<(u32, Money)> {
+        vec![
+            (12, Money::from_reais(50)),
+            (36, Money::from_reais(120)),
+            (60, Money::from_reais(200)),
+        ]
+    }
+
+    #[test]
+    fn test_tempo_de_casa_faixas() {
+        let faixas = faixas_anuenio();
+
+        assert_eq!(valor_por_tempo_de_casa(&faixas, 20), Money::from_reais(50));
+        assert_eq!(valor_por_tempo_de_casa(&faixas, 40), Money::from_reais(120));
+        assert_eq!(
+            valor_por_tempo_de_casa(&faixas, 100),
+            Money::from_reais(200)
+        );
+    }
+
+    #[test]
+    fn test_tempo_de_casa_bordas() {
+        let faixas = faixas_anuenio();
+
+        assert_eq!(valor_por_tempo_de_casa(&faixas, 11), Money::ZERO);
+        assert_eq!(valor_por_tempo_de_casa(&faixas, 12), Money::from_reais(50));
+        assert_eq!(valor_por_tempo_de_casa(&faixas, 35), Money::from_reais(50));
+        assert_eq!(valor_por_tempo_de_casa(&faixas, 36), Money::from_reais(120));
+        assert_eq!(valor_por_tempo_de_casa(&faixas, 60), Money::from_reais(200));
+    }
+
+    #[test]
+    fn test_tempo_de_casa_faixas_fora_de_ordem() {
+        let mut faixas = faixas_anuenio();
+        faixas.reverse();
+
+        assert_eq!(valor_por_tempo_de_casa(&faixas, 40), Money::from_reais(120));
+        assert_eq!(valor_por_tempo_de_casa(&[], 40), Money::ZERO);
+    }
+
+    #[test]
+    fn test_valor_dia_por_divisor() {
+        let salario = Money::from_reais(3000);
+
+        assert_eq!(DivisorDias::default(), DivisorDias::Comercial30);
+        assert_eq!(
+            valor_dia(salario, DivisorDias::Comercial30, 21),
+            Ok(Money::from_reais(100))
+        );
+        // 3000 / 21 = 142.857... → 142.86
+        assert_eq!(
+            valor_dia(salario, DivisorDias::DiasUteis, 21),
+            Ok(Money::parse("142.86").unwrap())
+        );
+        assert_eq!(
+            valor_dia(salario, DivisorDias::Personalizado(25), 21),
+            Ok(Money::from_reais(120))
+        );
+    }
+
+    #[test]
+    fn test_valor_dia_divisor_invalido() {
+        let salario = Money::from_reais(3000);
+
+        assert_eq!(
+            valor_dia(salario, DivisorDias::Personalizado(0), 21),
+            Err(ErroCalculo::DivisorInvalido(0))
+        );
+        assert_eq!(
+            valor_dia(salario, DivisorDias::DiasUteis, -1),
+            Err(ErroCalculo::DivisorInvalido(-1))
+        );
+    }
+
+    #[test]
+    fn test_desconto_faltas() {
+        let salario = Money::from_reais(3000);
+
+        let comercial = desconto_faltas(salario, 2, DivisorDias::Comercial30, 21).unwrap();
+        let uteis = desconto_faltas(salario, 2, DivisorDias::DiasUteis, 21).unwrap();
+
+        assert_eq!(comercial.valor, Money::from_reais(200));
+        assert_eq!(comercial.descricao, "Faltas");
+        assert_eq!(uteis.valor, Money::parse("285.72").unwrap());
+    }
+
+    #[test]
+    fn test_consignado_ate_quitacao() {
+        let mut consignado = Consignado {
+            parcela: Money::parse("250.00").unwrap(),
+            parcelas_restantes: 3,
+        };
+        assert_eq!(consignado.saldo_devedor(), Money::parse("750.00").unwrap());
+
+        let pagamentos: Vec<Money> = (0..4)
+            .map(|_| {
+                let deducoes = consignado.descontar_parcela().into_iter().collect();
+                calcular_valores(Money::from_reais(1000), Money::ZERO, 0, deducoes).pagamento_final
+            })
+            .collect();
+
+        assert_eq!(
+            pagamentos,
+            vec![
+                Money::from_reais(750),
+                Money::from_reais(750),
+                Money::from_reais(750),
+                Money::from_reais(1000), // quitado
+            ]
+        );
+        assert_eq!(consignado.parcelas_restantes, 0);
+        assert_eq!(consignado.saldo_devedor(), Money::ZERO);
+    }
+
+    #[test]
+    fn test_consignado_saldo_diminui_a_cada_mes() {
+        let mut consignado = Consignado {
+            parcela: Money::parse("99.90").unwrap(),
+            parcelas_restantes: 2,
+        };
+
+        let deducao = consignado.descontar_parcela().unwrap();
+
+        assert_eq!(deducao.valor, Money::parse("99.90").unwrap());
+        assert_eq!(deducao.descricao, "Consignado");
+        assert_eq!(consignado.saldo_devedor(), Money::parse("99.90").unwrap());
+        assert!(consignado.descontar_parcela().is_some());
+        assert!(consignado.descontar_parcela().is_none());
+    }
+
+    #[test]
+    fn test_data_quitacao_no_mesmo_ano() {
+        assert_eq!(data_quitacao(3, 5, 2024), (8, 2024));
+        assert_eq!(data_quitacao(0, 5, 2024), (5, 2024));
+        assert_eq!(data_quitacao(7, 5, 2024), (12, 2024));
+    }
+
+    #[test]
+    fn test_data_quitacao_cruzando_virada_de_ano() {
+        assert_eq!(data_quitacao(1, 12, 2024), (1, 2025));
+        assert_eq!(data_quitacao(3, 11, 2024), (2, 2025));
+        assert_eq!(data_quitacao(12, 1, 2024), (1, 2025));
+        assert_eq!(data_quitacao(48, 10, 2024), (10, 2028));
+    }
+
+    #[test]
+    fn test_data_quitacao_bate_com_consignado() {
+        let mut consignado = Consignado {
+            parcela: Money::from_reais(100),
+            parcelas_restantes: 4,
+        };
+        let (mut mes, mut ano) = (11, 2024);
+
+        // Desconta a parcela de novembro e anda mês a mês até quitar
+        consignado.descontar_parcela();
+        let quitacao = data_quitacao(consignado.parcelas_restantes, mes, ano);
+        while consignado.descontar_parcela().is_some() {
+            (mes, ano) = if mes == 12 {
+                (1, ano + 1)
+            } else {
+                (mes + 1, ano)
+            };
+        }
+        // O laço avança um mês por parcela descontada
+        assert_eq!((mes, ano), (2, 2025));
+        assert_eq!(quitacao, (2, 2025));
+    }
+}
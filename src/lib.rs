@@ -1,7 +1,64 @@
 use chrono::{Datelike, NaiveDate, Weekday};
+use std::collections::HashMap;
 use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+mod analise;
+mod config;
+mod erro;
+mod exportar;
+mod feriados;
+mod folha;
+#[cfg(feature = "serde")]
+pub mod money_serde;
+mod ponto;
+mod regra;
+mod relatorio;
+mod transporte;
+#[cfg(feature = "xlsx")]
+mod xlsx;
+
+pub use analise::{
+    acumular_resultados, calcular_lote, comparar_cenarios, consolidar_folha, media_movel,
+    serie_pagamentos, total_anual_transporte, total_lote, ResumoAcumulado, ResumoFolha,
+};
+pub use config::{
+    ler_argumentos, ler_config, ler_config_env, ler_config_parcial, ArgumentosCli,
+    ConfiguracaoPadrao,
+};
+pub use erro::{ErroCalculo, ErroParseMoney};
+pub use exportar::{resultado_para_csv, resultado_para_json, CABECALHO_CSV, CHAVES_CONTEXTO};
+pub use feriados::{
+    aviso_feriados_estaduais, feriados_estaduais, feriados_nacionais, nome_feriado, pascoa,
+    PoliticaEmenda, RegraFeriado,
+};
+pub use folha::{
+    bonus_por_meta, calcular_inss, corrigir_competencias, corrigir_valor, data_quitacao,
+    desconto_faltas, somar_convertendo, validar_limite_descontos, valor_dia,
+    valor_por_tempo_de_casa, Consignado, DivisorDias, FaixaINSS, TabelaINSS,
+    LIMITE_DESCONTOS_PADRAO,
+};
+pub use ponto::{processar_ponto, ResumoPonto};
+pub use regra::{RegraCalculo, RegraPadrao};
+pub use relatorio::{
+    formatar_resultado, formatar_resultado_brl, formatar_resultado_colorido,
+    formatar_resultado_em_idioma, gerar_recibo, relatorio_diferencial, resultado_duas_colunas,
+    resultado_tabela_unicode, resumo_chat,
+};
+pub use transporte::{
+    agrupar_por_semana_fiscal, calcular_transporte_com_sextas, calcular_transporte_por_perfil,
+    comparar_meios, conciliar_vale, custo_diario_integrado, custo_transporte_por_categorias,
+    descontar_atrasos_em_dias, economia_anual_hibrido, economia_home_office,
+    impacto_aumento_tarifa, CategoriaDia, ConciliacaoVale, CustoIntegrado, ParametrosDia,
+    PerfilViagens, RegraIntegracao, SemanaFiscal, TarifasPorCategoria, TransporteComSextas,
+    TransportePorPerfil,
+};
+#[cfg(feature = "xlsx")]
+pub use xlsx::exportar_xlsx;
+
+// Com a feature `serde`, serializa como inteiro de centavos: o
+// round-trip é exato, sem passar por ponto flutuante. Outros formatos
+// ficam em `money_serde`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Money(i64); // centavos
 
 impl Money {
@@ -19,30 +76,205 @@ impl Money {
         self.0
     }
 
-    pub fn parse(s: &str) -> Result<Self, String> {
+    /// Multiplica por um fator fracionário (0.08 para 8%) e arredonda ao
+    /// centavo, com o meio centavo para longe do zero: 10.01 × 0.5 = 5.01.
+    /// O fator é lido com até 9 casas decimais, para que 1.15 conte como
+    /// 1.15 e não como o 1.149999... do ponto flutuante.
+    pub fn mul_fracao(&self, fator: f64) -> Money {
+        const ESCALA: i128 = 1_000_000_000;
+
+        let fator = (fator * ESCALA as f64).round() as i128;
+        let produto = self.0 as i128 * fator;
+        let quociente = produto / ESCALA;
+        let resto = produto % ESCALA;
+
+        let arredondado = if resto.abs() * 2 >= ESCALA {
+            quociente + produto.signum()
+        } else {
+            quociente
+        };
+        Money(arredondado as i64)
+    }
+
+    /// Valor no padrão brasileiro, sem símbolo: "1.234,56", "-20,50".
+    pub fn format_brl(&self) -> String {
+        let digitos = (self.0.unsigned_abs() / 100).to_string();
+
+        let mut reais = String::new();
+        for (i, digito) in digitos.chars().enumerate() {
+            if i > 0 && (digitos.len() - i).is_multiple_of(3) {
+                reais.push('.');
+            }
+            reais.push(digito);
+        }
+
+        let sinal = if self.0 < 0 { "-" } else { "" };
+        format!("{}{},{:02}", sinal, reais, self.0.unsigned_abs() % 100)
+    }
+
+    /// Como `format_brl`, com o símbolo e o sinal antes dele: "-R$ 20,50".
+    pub fn format_brl_com_simbolo(&self) -> String {
+        let sinal = if self.0 < 0 { "-" } else { "" };
+        format!("{}R$ {}", sinal, Money(self.0.abs()).format_brl())
+    }
+
+    /// Quanto este valor representa de `total`, em percentual (50.0 =
+    /// metade). Sobre um total zero o percentual é zero.
+    pub fn percent_of(self, total: Money) -> f64 {
+        if total.0 == 0 {
+            return 0.0;
+        }
+
+        self.0 as f64 * 100.0 / total.0 as f64
+    }
+
+    /// Rateia o valor em `n` parcelas que somam exatamente o original; os
+    /// centavos que sobram vão para as primeiras. Negativos se dividem do
+    /// mesmo jeito (-10.00 em 3 dá -3.34, -3.33, -3.33) e `n` menor que 1
+    /// resulta em nenhuma parcela.
+    pub fn dividir(&self, n: i32) -> Vec<Money> {
+        if n < 1 {
+            return Vec::new();
+        }
+
+        let n = n as i64;
+        let base = self.0 / n;
+        let resto = (self.0 % n).abs();
+
+        (0..n)
+            .map(|i| {
+                if i < resto {
+                    Money(base + self.0.signum())
+                } else {
+                    Money(base)
+                }
+            })
+            .collect()
+    }
+
+    /// Combina reais e centavos vindos de campos separados. O sinal de
+    /// `reais` vale para o valor inteiro: `(-7, 50)` é -7.50.
+    pub fn from_reais_centavos(reais: i64, centavos: u8) -> Result<Self, ErroCalculo> {
+        if centavos >= 100 {
+            return Err(ErroCalculo::CentavosInvalidos(centavos));
+        }
+
+        let centavos = centavos as i64;
+        Ok(Money(if reais < 0 {
+            reais * 100 - centavos
+        } else {
+            reais * 100 + centavos
+        }))
+    }
+
+    pub fn parse(s: &str) -> Result<Self, ErroParseMoney> {
+        s.parse()
+    }
+
+    /// Como `parse`, aceitando também o sinal depois do valor, como vem
+    /// em extratos de alguns bancos: "20,50-" e "50DB" são negativos,
+    /// "100CR" é positivo. O valor antes do sufixo não pode ter sinal.
+    pub fn parse_permissivo(s: &str) -> Result<Self, ErroParseMoney> {
+        let s = s.trim();
+        let maiusculo = s.to_ascii_uppercase();
+
+        let (valor, negativo) = if maiusculo.ends_with("CR") {
+            (&s[..s.len() - 2], false)
+        } else if maiusculo.ends_with("DB") {
+            (&s[..s.len() - 2], true)
+        } else if let Some(valor) = s.strip_suffix('-') {
+            (valor, true)
+        } else {
+            return Money::parse(s);
+        };
+
+        let valor = valor.trim_end();
+        // Só o sufixo não é valor: "-" sozinho não pode virar zero
+        if valor.is_empty() {
+            return Err(ErroParseMoney::NumeroInvalido);
+        }
+        if valor.starts_with(['-', '(']) {
+            return Err(ErroParseMoney::SinalDuplicado);
+        }
+        let valor = Money::parse(valor)?;
+        Ok(if negativo { Money(-valor.0) } else { valor })
+    }
+}
+
+impl std::str::FromStr for Money {
+    type Err = ErroParseMoney;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         let s = s.trim();
+
+        // Campos de CSV podem vir entre aspas simples ou duplas
+        let s = match s.chars().next() {
+            Some(aspa @ ('"' | '\'')) => {
+                let inner = s[1..]
+                    .strip_suffix(aspa)
+                    .ok_or(ErroParseMoney::AspasDesbalanceadas)?;
+                if inner.contains(['"', '\'']) {
+                    return Err(ErroParseMoney::AspaInesperada);
+                }
+                inner.trim()
+            }
+            _ if s.ends_with(['"', '\'']) => return Err(ErroParseMoney::AspasDesbalanceadas),
+            _ => s,
+        };
+
+        // Prefixo de moeda opcional, com ou sem espaço: "R$ 7.50", "R$7.50"
+        let s = match s.strip_prefix("R$") {
+            Some("") => return Err(ErroParseMoney::ValorAusenteAposSimbolo),
+            Some(rest) => rest.trim_start(),
+            None => s,
+        };
+
         if s.is_empty() {
             return Ok(Money::ZERO);
         }
 
-        let is_negative = s.starts_with('-');
-        let s = if is_negative { &s[1..] } else { s };
+        // Notação contábil: "(20.50)" equivale a "-20.50"
+        let (is_negative, s) = if let Some(inner) = s.strip_prefix('(') {
+            let inner = inner
+                .strip_suffix(')')
+                .ok_or(ErroParseMoney::ParentesesDesbalanceados)?;
+            let inner = inner.trim();
+            if inner.starts_with('-') || inner.contains(['(', ')']) {
+                return Err(ErroParseMoney::NotacaoContabilInvalida);
+            }
+            (true, inner)
+        } else if s.ends_with(')') {
+            return Err(ErroParseMoney::ParentesesDesbalanceados);
+        } else if let Some(rest) = s.strip_prefix('-') {
+            (true, rest)
+        } else {
+            (false, s)
+        };
+
+        let s = normalizar_separadores(s)?;
+        let s = s.as_str();
 
         if let Some(dot_pos) = s.find('.') {
             let (reais_str, cents_str) = s.split_at(dot_pos);
             let cents_str = &cents_str[1..]; // remove the dot
 
-            let reais: i64 = reais_str.parse().map_err(|_| "Invalid reais part")?;
+            let reais: i64 = reais_str
+                .parse()
+                .map_err(|_| ErroParseMoney::ParteReaisInvalida)?;
 
             let cents = if cents_str.is_empty() {
                 0
             } else if cents_str.len() == 1 {
-                let digit: i64 = cents_str.parse().map_err(|_| "Invalid cents part")?;
+                let digit: i64 = cents_str
+                    .parse()
+                    .map_err(|_| ErroParseMoney::ParteCentavosInvalida)?;
                 digit * 10
             } else if cents_str.len() == 2 {
-                cents_str.parse().map_err(|_| "Invalid cents part")?
+                cents_str
+                    .parse()
+                    .map_err(|_| ErroParseMoney::ParteCentavosInvalida)?
             } else {
-                return Err("Too many decimal places".to_string());
+                return Err(ErroParseMoney::MuitasCasasDecimais);
             };
 
             let total_centavos = reais * 100 + cents;
@@ -52,7 +284,7 @@ impl Money {
                 total_centavos
             }))
         } else {
-            let reais: i64 = s.parse().map_err(|_| "Invalid number")?;
+            let reais: i64 = s.parse().map_err(|_| ErroParseMoney::NumeroInvalido)?;
             let total_centavos = reais * 100;
             Ok(Money(if is_negative {
                 -total_centavos
@@ -63,11 +295,81 @@ impl Money {
     }
 }
 
+impl TryFrom<&str> for Money {
+    type Error = ErroParseMoney;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+// Converte para a forma "1234.56": o último separador ('.' ou ',') é o
+// decimal e os demais são de milhar. Um '.' único seguido de exatamente
+// três dígitos é tratado como milhar ("1.234" = 1234).
+fn normalizar_separadores(s: &str) -> Result<String, ErroParseMoney> {
+    let Some(ultimo) = s.rfind(['.', ',']) else {
+        return Ok(s.to_string());
+    };
+    let separador = s[ultimo..].chars().next().unwrap();
+    let ocorrencias = s.matches(separador).count();
+    let outro = if separador == '.' { ',' } else { '.' };
+
+    let decimal = if s.contains(outro) {
+        Some(ultimo)
+    } else if ocorrencias > 1 || (separador == '.' && s.len() - ultimo - 1 == 3) {
+        None
+    } else {
+        Some(ultimo)
+    };
+
+    let (inteira, centavos) = match decimal {
+        Some(posicao) => (&s[..posicao], Some(&s[posicao + 1..])),
+        None => (s, None),
+    };
+    let milhar = if decimal.is_some() { outro } else { separador };
+
+    let mut grupos = inteira.split(milhar);
+    let primeiro = grupos.next().unwrap_or_default();
+    let mut digitos = primeiro.to_string();
+    for grupo in grupos {
+        if primeiro.is_empty() || primeiro.len() > 3 || grupo.len() != 3 {
+            return Err(ErroParseMoney::SeparadorMilharInvalido);
+        }
+        digitos.push_str(grupo);
+    }
+
+    match centavos {
+        Some(centavos) if centavos.contains(['.', ',']) => {
+            Err(ErroParseMoney::SeparadorMilharInvalido)
+        }
+        Some(centavos) => Ok(format!("{}.{}", digitos, centavos)),
+        None => Ok(digitos),
+    }
+}
+
+// A precisão do formatter escolhe as casas (`{:.0}` → "123", padrão 2).
+// Menos de 2 casas arredonda metade para longe de zero; mais de 2
+// completa com zeros.
 impl fmt::Display for Money {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let reais = self.0 / 100;
-        let centavos = (self.0 % 100).abs();
-        write!(f, "{}.{:02}", reais, centavos)
+        let casas = f.precision().unwrap_or(2);
+        let centavos = self.0.unsigned_abs();
+
+        let (unidades, escala) = match casas {
+            0 => ((centavos + 50) / 100, 1),
+            1 => ((centavos + 5) / 10, 10),
+            _ => (centavos, 100),
+        };
+
+        // O sinal vem à parte: -0.50 não tem reais negativos para carregá-lo,
+        // e o que arredonda para zero sai sem sinal
+        let sinal = if self.0 < 0 && unidades != 0 { "-" } else { "" };
+        write!(f, "{}{}", sinal, unidades / escala)?;
+        match casas {
+            0 => Ok(()),
+            1 => write!(f, ".{}", unidades % escala),
+            _ => write!(f, ".{:02}{}", unidades % escala, "0".repeat(casas - 2)),
+        }
     }
 }
 
@@ -87,6 +389,18 @@ impl std::ops::Sub for Money {
     }
 }
 
+impl std::iter::Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, |acc, valor| acc + valor)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Money> for Money {
+    fn sum<I: Iterator<Item = &'a Money>>(iter: I) -> Money {
+        iter.copied().sum()
+    }
+}
+
 impl std::ops::Mul<i32> for Money {
     type Output = Money;
 
@@ -95,154 +409,1102 @@ impl std::ops::Mul<i32> for Money {
     }
 }
 
-pub fn contar_dias_uteis(mes: u32, ano: i32) -> Result<i32, String> {
-    if mes < 1 || mes > 12 {
-        return Err("Mês deve estar entre 1 e 12".to_string());
+// Jornada padrão, de segunda a sexta
+pub(crate) const JORNADA_PADRAO: [Weekday; 5] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+];
+
+// Dia de trabalho na jornada padrão; o mesmo critério das contagens
+fn eh_dia_util(data: &NaiveDate) -> bool {
+    JORNADA_PADRAO.contains(&data.weekday())
+}
+
+fn validar_mes_ano(mes: u32, ano: i32) -> Result<(), ErroCalculo> {
+    if !(1..=12).contains(&mes) {
+        return Err(ErroCalculo::MesInvalido(mes));
     }
 
-    if ano < 1900 || ano > 2100 {
-        return Err("Ano deve estar entre 1900 e 2100".to_string());
+    if !(1900..=2100).contains(&ano) {
+        return Err(ErroCalculo::AnoInvalido(ano));
     }
 
-    let primeiro_dia = match NaiveDate::from_ymd_opt(ano, mes, 1) {
-        Some(date) => date,
-        None => return Err("Data inválida".to_string()),
-    };
+    Ok(())
+}
+
+/// Rejeita competências mais de `meses_futuros_max` meses à frente de
+/// `hoje`, para pegar erros de digitação como 2099 no lugar de 2024.
+/// Competências passadas são sempre aceitas. `hoje` vem de fora para que
+/// o relógio possa ser fixado em testes.
+pub fn validar_competencia_razoavel(
+    mes: u32,
+    ano: i32,
+    hoje: NaiveDate,
+    meses_futuros_max: i32,
+) -> Result<(), ErroCalculo> {
+    validar_mes_ano(mes, ano)?;
+
+    let meses_a_frente = (ano - hoje.year()) * 12 + mes as i32 - hoje.month() as i32;
+    if meses_a_frente > meses_futuros_max {
+        return Err(ErroCalculo::CompetenciaDistante {
+            meses_a_frente,
+            maximo: meses_futuros_max,
+        });
+    }
+
+    Ok(())
+}
 
-    let proximo_mes = if mes == 12 { 1 } else { mes + 1 };
-    let proximo_ano = if mes == 12 { ano + 1 } else { ano };
+pub fn contar_dias_uteis(mes: u32, ano: i32) -> Result<i32, ErroCalculo> {
+    contar_dias_uteis_com_jornada(mes, ano, &JORNADA_PADRAO)
+}
 
-    let ultimo_dia = match NaiveDate::from_ymd_opt(proximo_ano, proximo_mes, 1) {
-        Some(date) => date.pred_opt().unwrap(),
-        None => return Err("Data inválida".to_string()),
-    };
+/// Como `contar_dias_uteis`, mas considerando úteis apenas os dias da
+/// semana em `dias_trabalho` (incluir sábado, tirar a segunda, ...).
+pub fn contar_dias_uteis_com_jornada(
+    mes: u32,
+    ano: i32,
+    dias_trabalho: &[Weekday],
+) -> Result<i32, ErroCalculo> {
+    Ok(listar_dias_uteis_com_jornada(mes, ano, dias_trabalho)?.len() as i32)
+}
 
-    let mut dias_uteis = 0;
-    let mut data_atual = primeiro_dia;
+/// Datas de cada dia útil (segunda a sexta) do mês, em ordem
+/// cronológica, do dia 1 ao último dia do mês.
+pub fn listar_dias_uteis(mes: u32, ano: i32) -> Result<Vec<NaiveDate>, ErroCalculo> {
+    listar_dias_uteis_com_jornada(mes, ano, &JORNADA_PADRAO)
+}
 
-    while data_atual <= ultimo_dia {
-        let dia_semana = data_atual.weekday();
-        if dia_semana != Weekday::Sat && dia_semana != Weekday::Sun {
-            dias_uteis += 1;
-        }
-        data_atual = data_atual.succ_opt().unwrap();
+// Datas do mês que caem nos dias da semana de `dias_trabalho`, em ordem
+fn listar_dias_uteis_com_jornada(
+    mes: u32,
+    ano: i32,
+    dias_trabalho: &[Weekday],
+) -> Result<Vec<NaiveDate>, ErroCalculo> {
+    validar_mes_ano(mes, ano)?;
+
+    let primeiro_dia = NaiveDate::from_ymd_opt(ano, mes, 1).ok_or(ErroCalculo::DataInvalida)?;
+
+    Ok(primeiro_dia
+        .iter_days()
+        .take_while(|data| data.month() == mes)
+        .filter(|data| dias_trabalho.contains(&data.weekday()))
+        .collect())
+}
+
+/// Feriados nacionais (fixos e móveis) do ano que caem em dia útil,
+/// somados mês a mês, para planejar o ano inteiro.
+pub fn feriados_uteis_no_ano(ano: i32) -> Result<i32, ErroCalculo> {
+    let feriados = feriados::feriados_nacionais(ano);
+
+    let mut total = 0;
+    for mes in 1..=12 {
+        total += listar_dias_uteis(mes, ano)?
+            .iter()
+            .filter(|data| feriados.contains(data))
+            .count() as i32;
+    }
+    Ok(total)
+}
+
+/// Dias úteis de todos os meses de `ano_inicio` a `ano_fim`, inclusive,
+/// indexados por (mês, ano), para servidores que preferem calcular tudo
+/// de uma vez a contar a cada pedido.
+pub fn pre_computar_dias_uteis(
+    ano_inicio: i32,
+    ano_fim: i32,
+) -> Result<HashMap<(u32, i32), i32>, ErroCalculo> {
+    if ano_fim < ano_inicio {
+        return Err(ErroCalculo::PeriodoInvalido);
     }
+    validar_mes_ano(1, ano_inicio)?;
+    validar_mes_ano(1, ano_fim)?;
 
+    let mut dias_uteis = HashMap::new();
+    for ano in ano_inicio..=ano_fim {
+        for mes in 1..=12 {
+            dias_uteis.insert((mes, ano), contar_dias_uteis(mes, ano)?);
+        }
+    }
     Ok(dias_uteis)
 }
 
+/// Dias corridos de `inicio` a `fim`, inclusive, contando fins de semana.
+pub fn contar_dias_corridos(inicio: NaiveDate, fim: NaiveDate) -> Result<i32, ErroCalculo> {
+    if fim < inicio {
+        return Err(ErroCalculo::PeriodoInvalido);
+    }
+
+    Ok((fim - inicio).num_days() as i32 + 1)
+}
+
+/// Dias úteis (segunda a sexta) de `inicio` a `fim`, inclusive, para
+/// proratear períodos que não são o mês cheio, como uma admissão no dia 10.
+pub fn contar_dias_uteis_periodo(inicio: NaiveDate, fim: NaiveDate) -> Result<i32, ErroCalculo> {
+    if fim < inicio {
+        return Err(ErroCalculo::PeriodoInvalido);
+    }
+
+    Ok(dias_uteis_no_intervalo(inicio, fim, &[]))
+}
+
+// Dias úteis de `inicio` a `fim`, inclusive, fora os feriados informados
+fn dias_uteis_no_intervalo(inicio: NaiveDate, fim: NaiveDate, feriados: &[NaiveDate]) -> i32 {
+    inicio
+        .iter_days()
+        .take_while(|data| *data <= fim)
+        .filter(eh_dia_util)
+        .filter(|data| !feriados.contains(data))
+        .count() as i32
+}
+
+/// Dias úteis do primeiro dia do mês de `ate` até `ate`, inclusive.
+pub fn dias_uteis_decorridos(ate: NaiveDate, feriados: &[NaiveDate]) -> Result<i32, ErroCalculo> {
+    validar_mes_ano(ate.month(), ate.year())?;
+    let primeiro_dia = ate.with_day(1).ok_or(ErroCalculo::DataInvalida)?;
+
+    Ok(dias_uteis_no_intervalo(primeiro_dia, ate, feriados))
+}
+
+/// Dias úteis depois de `apos` até o fim do mês. Somado a
+/// `dias_uteis_decorridos` da mesma data, dá o total do mês.
+pub fn dias_uteis_restantes(apos: NaiveDate, feriados: &[NaiveDate]) -> Result<i32, ErroCalculo> {
+    validar_mes_ano(apos.month(), apos.year())?;
+    let ultimo_dia =
+        feriados::ultimo_dia_do_mes(apos.month(), apos.year()).ok_or(ErroCalculo::DataInvalida)?;
+
+    match apos.succ_opt() {
+        Some(seguinte) => Ok(dias_uteis_no_intervalo(seguinte, ultimo_dia, feriados)),
+        None => Ok(0),
+    }
+}
+
+/// Feriados nacionais do mês que caem em dia útil, em ordem; os de fim de
+/// semana ficam de fora porque já não contam como dia útil.
+pub fn feriados_nacionais_em_dias_uteis(mes: u32, ano: i32) -> Result<Vec<NaiveDate>, ErroCalculo> {
+    validar_mes_ano(mes, ano)?;
+
+    Ok(feriados::feriados_nacionais(ano)
+        .into_iter()
+        .filter(|data| data.month() == mes)
+        .filter(eh_dia_util)
+        .collect())
+}
+
+/// Dias úteis do mês já descontando os feriados nacionais.
+pub fn contar_dias_uteis_com_feriados(mes: u32, ano: i32) -> Result<i32, ErroCalculo> {
+    Ok(contar_dias_uteis(mes, ano)? - feriados_nacionais_em_dias_uteis(mes, ano)?.len() as i32)
+}
+
+/// Dias úteis do mês sem trabalho: os feriados que caem em dia útil mais
+/// os dias-ponte da política. Serve como `feriados_deduzidos`.
+pub fn dias_nao_trabalhados(
+    mes: u32,
+    ano: i32,
+    feriados: &[NaiveDate],
+    politica: PoliticaEmenda,
+) -> Result<i32, ErroCalculo> {
+    validar_mes_ano(mes, ano)?;
+
+    let mut dias: Vec<NaiveDate> = feriados
+        .iter()
+        .copied()
+        .chain(politica.dias_ponte(feriados))
+        .filter(|data| (data.month(), data.year()) == (mes, ano))
+        .filter(eh_dia_util)
+        .collect();
+    dias.sort();
+    dias.dedup();
+    Ok(dias.len() as i32)
+}
+
+/// Diferença entre os dias úteis do mesmo mês em dois anos
+/// (`ano_a` − `ano_b`): positiva quando `ano_a` tem mais dias úteis.
+pub fn diferenca_dias_uteis(mes: u32, ano_a: i32, ano_b: i32) -> Result<i32, ErroCalculo> {
+    Ok(contar_dias_uteis(mes, ano_a)? - contar_dias_uteis(mes, ano_b)?)
+}
+
+pub fn contar_dia_semana(mes: u32, ano: i32, dia_semana: Weekday) -> Result<i32, ErroCalculo> {
+    validar_mes_ano(mes, ano)?;
+
+    // Todo dia da semana aparece 4 ou 5 vezes em um mês
+    match NaiveDate::from_weekday_of_month_opt(ano, mes, dia_semana, 5) {
+        Some(_) => Ok(5),
+        None => Ok(4),
+    }
+}
+
+/// Quantas vezes o dia de pagamento semanal cai no mês (4 ou 5).
+pub fn semanas_de_pagamento(
+    mes: u32,
+    ano: i32,
+    dia_pagamento: Weekday,
+) -> Result<i32, ErroCalculo> {
+    contar_dia_semana(mes, ano, dia_pagamento)
+}
+
+pub fn eh_bissexto(ano: i32) -> bool {
+    (ano % 4 == 0 && ano % 100 != 0) || ano % 400 == 0
+}
+
+/// Total de dias corridos do mês (28 a 31), considerando ano bissexto.
+pub fn dias_no_mes(mes: u32, ano: i32) -> Result<u32, ErroCalculo> {
+    validar_mes_ano(mes, ano)?;
+
+    feriados::ultimo_dia_do_mes(mes, ano)
+        .map(|data| data.day())
+        .ok_or(ErroCalculo::DataInvalida)
+}
+
+pub fn trimestre_do_mes(mes: u32) -> Result<u8, ErroCalculo> {
+    if !(1..=12).contains(&mes) {
+        return Err(ErroCalculo::MesInvalido(mes));
+    }
+
+    Ok(((mes - 1) / 3 + 1) as u8)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Idioma {
+    #[default]
+    Pt,
+    En,
+}
+
 pub fn obter_nome_mes(mes: u32) -> &'static str {
+    obter_nome_mes_lang(mes, Idioma::Pt)
+}
+
+/// Como `obter_nome_mes`, no idioma pedido ("Novembro" ou "November").
+pub fn obter_nome_mes_lang(mes: u32, idioma: Idioma) -> &'static str {
+    const PT: [&str; 12] = [
+        "Janeiro",
+        "Fevereiro",
+        "Março",
+        "Abril",
+        "Maio",
+        "Junho",
+        "Julho",
+        "Agosto",
+        "Setembro",
+        "Outubro",
+        "Novembro",
+        "Dezembro",
+    ];
+    const EN: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+
+    let nomes = match idioma {
+        Idioma::Pt => &PT,
+        Idioma::En => &EN,
+    };
     match mes {
-        1 => "Janeiro",
-        2 => "Fevereiro",
-        3 => "Março",
-        4 => "Abril",
-        5 => "Maio",
-        6 => "Junho",
-        7 => "Julho",
-        8 => "Agosto",
-        9 => "Setembro",
-        10 => "Outubro",
-        11 => "Novembro",
-        12 => "Dezembro",
+        1..=12 => nomes[mes as usize - 1],
         _ => unreachable!(),
     }
 }
 
+// Nomes de mês são `&'static str`; na desserialização o texto é trocado
+// pelo nome correspondente de `obter_nome_mes`. O alias evita que o derive
+// trate o campo como emprestado do deserializer (o que exigiria 'de: 'static)
+type NomeMes = Option<&'static str>;
+
+#[cfg(feature = "serde")]
+fn deserializar_nome_mes<'de, D>(deserializer: D) -> Result<NomeMes, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let nome: Option<String> = serde::Deserialize::deserialize(deserializer)?;
+
+    nome.map(|nome| {
+        (1..=12)
+            .map(obter_nome_mes)
+            .find(|&mes| mes == nome)
+            .ok_or_else(|| serde::de::Error::custom(format!("mês desconhecido: {}", nome)))
+    })
+    .transpose()
+}
+
+// Mês e ano ficam ausentes quando os dias úteis foram informados já contados
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InformacaoCalendario {
-    pub mes: u32,
-    pub ano: i32,
-    pub nome_mes: &'static str,
+    pub mes: Option<u32>,
+    pub ano: Option<i32>,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserializar_nome_mes"))]
+    pub nome_mes: NomeMes,
     pub dias_uteis_mes: i32,
     pub feriados_deduzidos: i32,
+    // Igual a `feriados_deduzidos` quando só a quantidade foi informada
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub feriados_informados: i32,
     pub dias_trabalhados: i32,
+    // Vazio quando só a quantidade de feriados foi informada
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub feriados: Vec<FeriadoCalendario>,
+}
+
+// Feriados em fim de semana são listados, mas não reduzem os dias úteis
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeriadoCalendario {
+    pub data: NaiveDate,
+    pub nome: Option<String>,
+    pub deduzido: bool,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Deducao {
     pub valor: Money,
     pub descricao: String,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Provento {
+    pub valor: Money,
+    pub descricao: String,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ResultadoCalculo {
     pub taxa_fixa: Money,
+    pub componentes_fixos: Vec<(String, Money)>, // vazio quando a taxa fixa é única
     pub taxa_transporte: Money,
     pub dias_trabalhados: i32,
+    pub dias_trabalhados_fracionarios: f64, // igual a dias_trabalhados quando não há meio dia
+    pub viagens_por_dia: i32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub meios_dias: i32, // uma viagem cada, além dos dias_trabalhados inteiros
     pub custo_transporte: Money,
+    pub proventos: Vec<Provento>,
+    pub proventos_total: Money,
     pub deducoes: Vec<Deducao>,
     pub deducoes_total: Money,
     pub pagamento_final: Money,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub complemento_piso: Money, // já incluído nos proventos
     pub calendario: Option<InformacaoCalendario>,
 }
 
+impl ResultadoCalculo {
+    pub fn adicionar_provento(&mut self, provento: Provento) {
+        self.proventos_total = self.proventos_total + provento.valor;
+        self.pagamento_final = self.pagamento_final + provento.valor;
+        self.proventos.push(provento);
+    }
+
+    /// Concede o bônus como provento somente quando não há faltas no mês.
+    /// Retorna se o bônus foi concedido.
+    pub fn aplicar_bonus_assiduidade(&mut self, bonus: Money, faltas: i32) -> bool {
+        if faltas != 0 {
+            return false;
+        }
+
+        self.adicionar_provento(Provento {
+            valor: bonus,
+            descricao: "Bônus de assiduidade".to_string(),
+        });
+        true
+    }
+
+    /// Reembolsa o estacionamento dos dias trabalhados como provento.
+    /// Retorna o valor reembolsado.
+    pub fn aplicar_estacionamento(&mut self, estacionamento_por_dia: Money) -> Money {
+        let valor = estacionamento_por_dia * self.dias_trabalhados;
+
+        self.adicionar_provento(Provento {
+            valor,
+            descricao: "Estacionamento".to_string(),
+        });
+        valor
+    }
+
+    /// Arredonda o pagamento final para cima até o real inteiro, em favor
+    /// do trabalhador. O ajuste entra como provento e é devolvido; sem
+    /// centavos nada muda e o ajuste é zero.
+    pub fn arredondar_para_cima(&mut self) -> Money {
+        let centavos = self.pagamento_final.to_centavos().rem_euclid(100);
+        if centavos == 0 {
+            return Money::ZERO;
+        }
+
+        let ajuste = Money::from_centavos(100 - centavos);
+        self.adicionar_provento(Provento {
+            valor: ajuste,
+            descricao: "Arredondamento".to_string(),
+        });
+        ajuste
+    }
+
+    /// Garante o piso contratual: se o pagamento final ficar abaixo dele,
+    /// a diferença entra como provento e fica em `complemento_piso`. Sem
+    /// piso, ou com o pagamento já acima dele, nada muda. Retorna o
+    /// complemento.
+    pub fn aplicar_piso(&mut self, piso: Option<Money>) -> Money {
+        let complemento = match piso {
+            Some(piso) if self.pagamento_final < piso => piso - self.pagamento_final,
+            _ => return Money::ZERO,
+        };
+
+        self.adicionar_provento(Provento {
+            valor: complemento,
+            descricao: "Complemento de piso".to_string(),
+        });
+        self.complemento_piso = self.complemento_piso + complemento;
+        complemento
+    }
+
+    /// Cada componente (taxa fixa, transporte, proventos e deduções) com
+    /// seu percentual sobre o bruto, que é tudo menos as deduções. Os
+    /// percentuais dos componentes que não são deduções somam 100%.
+    pub fn componentes_percentuais(&self) -> Vec<(String, Money, f64)> {
+        let bruto = self.taxa_fixa + self.custo_transporte + self.proventos_total;
+        let rotulo = |descricao: &str, padrao: &str| {
+            if descricao.is_empty() {
+                padrao.to_string()
+            } else {
+                descricao.to_string()
+            }
+        };
+
+        let mut componentes = vec![
+            ("Taxa fixa".to_string(), self.taxa_fixa),
+            ("Transporte".to_string(), self.custo_transporte),
+        ];
+        componentes.extend(
+            self.proventos
+                .iter()
+                .map(|p| (rotulo(&p.descricao, "Provento"), p.valor)),
+        );
+        componentes.extend(
+            self.deducoes
+                .iter()
+                .map(|d| (rotulo(&d.descricao, "Dedução"), d.valor)),
+        );
+
+        componentes
+            .into_iter()
+            .map(|(rotulo, valor)| {
+                let percentual = valor.percent_of(bruto);
+                (rotulo, valor, percentual)
+            })
+            .collect()
+    }
+
+    /// Benefício pago por dia corrido (diária de hospedagem, por exemplo),
+    /// independente dos dias úteis trabalhados.
+    pub fn aplicar_diaria(&mut self, valor_por_dia: Money, dias_corridos: i32) -> Money {
+        let valor = valor_por_dia * dias_corridos;
+
+        self.adicionar_provento(Provento {
+            valor,
+            descricao: "Diária".to_string(),
+        });
+        valor
+    }
+}
+
+/// Consolida duas competências. Os valores monetários, os dias
+/// trabalhados e as listas de proventos e deduções são somados. Como a
+/// soma não corresponde a um único mês, o calendário do resultado fica
+/// `None`; a taxa de transporte e as viagens por dia só são mantidas
+/// quando são as mesmas nos dois lados e, caso contrário, ficam zeradas.
+/// Componentes fixos com o mesmo nome são somados; se só um lado tem
+/// componentes, a taxa fixa do outro entra como o componente "Taxa fixa",
+/// para que os componentes continuem somando a taxa fixa.
+impl std::ops::Add for ResultadoCalculo {
+    type Output = ResultadoCalculo;
+
+    fn add(mut self, other: ResultadoCalculo) -> ResultadoCalculo {
+        if self.taxa_transporte != other.taxa_transporte {
+            self.taxa_transporte = Money::ZERO;
+        }
+        if self.viagens_por_dia != other.viagens_por_dia {
+            self.viagens_por_dia = 0;
+        }
+
+        if !self.componentes_fixos.is_empty() || !other.componentes_fixos.is_empty() {
+            let mut componentes = Vec::new();
+            for lado in [&self, &other] {
+                if lado.componentes_fixos.is_empty() && lado.taxa_fixa != Money::ZERO {
+                    componentes.push(("Taxa fixa".to_string(), lado.taxa_fixa));
+                } else {
+                    componentes.extend(lado.componentes_fixos.iter().cloned());
+                }
+            }
+            self.componentes_fixos = mesclar_componentes(componentes);
+        }
+        self.taxa_fixa = self.taxa_fixa + other.taxa_fixa;
+        self.dias_trabalhados += other.dias_trabalhados;
+        self.dias_trabalhados_fracionarios += other.dias_trabalhados_fracionarios;
+        self.meios_dias += other.meios_dias;
+        self.custo_transporte = self.custo_transporte + other.custo_transporte;
+        self.proventos.extend(other.proventos);
+        self.proventos_total = self.proventos_total + other.proventos_total;
+        self.deducoes.extend(other.deducoes);
+        self.deducoes_total = self.deducoes_total + other.deducoes_total;
+        self.pagamento_final = self.pagamento_final + other.pagamento_final;
+        self.complemento_piso = self.complemento_piso + other.complemento_piso;
+        self.calendario = None;
+        self
+    }
+}
+
+// Soma os componentes de mesmo nome, na ordem em que aparecem pela
+// primeira vez
+fn mesclar_componentes(componentes: Vec<(String, Money)>) -> Vec<(String, Money)> {
+    let mut mesclados: Vec<(String, Money)> = Vec::new();
+    for (nome, valor) in componentes {
+        match mesclados
+            .iter_mut()
+            .find(|(existente, _)| *existente == nome)
+        {
+            Some((_, total)) => *total = *total + valor,
+            None => mesclados.push((nome, valor)),
+        }
+    }
+    mesclados
+}
+
+pub fn calcular_transporte_simples(taxa_transporte: Money, dias: i32, viagens: i32) -> Money {
+    taxa_transporte * dias * viagens
+}
+
+// Ida e volta
+const VIAGENS_POR_DIA_PADRAO: i32 = 2;
+
 pub fn calcular_valores(
     taxa_fixa: Money,
     taxa_transporte: Money,
     dias_trabalhados: i32,
     deducoes: Vec<Deducao>,
 ) -> ResultadoCalculo {
-    let custo_transporte = taxa_transporte * dias_trabalhados * 2;
-    let deducoes_total = deducoes.iter().fold(Money::ZERO, |acc, d| acc + d.valor);
+    calcular_valores_com_viagens(
+        taxa_fixa,
+        taxa_transporte,
+        dias_trabalhados,
+        VIAGENS_POR_DIA_PADRAO,
+        deducoes,
+    )
+}
+
+/// Como `calcular_valores`, mas com o número de viagens por dia informado
+/// (1 para quem só faz um trajeto, 4 para quem almoça em casa).
+pub fn calcular_valores_com_viagens(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    dias_trabalhados: i32,
+    viagens_por_dia: i32,
+    deducoes: Vec<Deducao>,
+) -> ResultadoCalculo {
+    let custo_transporte =
+        calcular_transporte_simples(taxa_transporte, dias_trabalhados, viagens_por_dia);
+    let deducoes_total = deducoes.iter().map(|d| d.valor).sum();
     let pagamento_final = taxa_fixa + custo_transporte - deducoes_total;
 
     ResultadoCalculo {
         taxa_fixa,
+        componentes_fixos: Vec::new(),
         taxa_transporte,
         dias_trabalhados,
+        dias_trabalhados_fracionarios: dias_trabalhados as f64,
+        viagens_por_dia,
+        meios_dias: 0,
         custo_transporte,
+        proventos: Vec::new(),
+        proventos_total: Money::ZERO,
         deducoes,
         deducoes_total,
         pagamento_final,
+        complemento_piso: Money::ZERO,
         calendario: None,
     }
 }
 
-pub fn calcular_valores_com_calendario(
+// Sobre o que incidem as deduções percentuais
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BaseDeducao {
+    #[default]
+    Total, // taxa fixa + transporte
+    ApenasTaxaFixa,
+}
+
+// Quando os centavos das deduções percentuais são arredondados
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ArredondamentoDeducoes {
+    #[default]
+    NaSoma, // soma os percentuais exatos e arredonda uma vez
+    PorItem, // arredonda cada dedução e soma os valores já arredondados
+}
+
+/// Como `calcular_valores`, com deduções percentuais (descrição e
+/// percentual, 8.0 = 8%) além das de valor fixo. Os percentuais incidem
+/// sobre a `base`, antes de qualquer dedução, e entram no resultado como
+/// deduções comuns depois das fixas. O total é arredondado uma vez, como
+/// em `ArredondamentoDeducoes::NaSoma`.
+pub fn calcular_valores_com_deducoes_percentuais(
     taxa_fixa: Money,
     taxa_transporte: Money,
-    mes: u32,
-    ano: i32,
-    feriados_deduzidos: i32,
+    dias_trabalhados: i32,
     deducoes: Vec<Deducao>,
-) -> Result<ResultadoCalculo, String> {
-    let dias_uteis_mes = contar_dias_uteis(mes, ano)?;
-    let dias_trabalhados = (dias_uteis_mes - feriados_deduzidos).max(0);
-
-    let custo_transporte = taxa_transporte * dias_trabalhados * 2;
-    let deducoes_total = deducoes.iter().fold(Money::ZERO, |acc, d| acc + d.valor);
-    let pagamento_final = taxa_fixa + custo_transporte - deducoes_total;
-
-    let calendario = InformacaoCalendario {
-        mes,
-        ano,
-        nome_mes: obter_nome_mes(mes),
-        dias_uteis_mes,
-        feriados_deduzidos,
-        dias_trabalhados,
-    };
-
-    Ok(ResultadoCalculo {
+    percentuais: &[(String, f64)],
+    base: BaseDeducao,
+) -> ResultadoCalculo {
+    calcular_valores_com_deducoes_percentuais_e_arredondamento(
         taxa_fixa,
         taxa_transporte,
         dias_trabalhados,
-        custo_transporte,
         deducoes,
-        deducoes_total,
-        pagamento_final,
-        calendario: Some(calendario),
-    })
+        percentuais,
+        base,
+        ArredondamentoDeducoes::default(),
+    )
 }
 
-pub fn obter_valor_numerico(prompt: &str) -> Money {
+/// Como `calcular_valores_com_deducoes_percentuais`, escolhendo o
+/// arredondamento. Em `PorItem` cada dedução é arredondada ao centavo e o
+/// total é a soma delas, como nos sistemas de folha que arredondam por
+/// item. Em `NaSoma` o total dos percentuais é arredondado uma vez e a
+/// diferença para a soma dos itens arredondados fica na última dedução
+/// percentual, para que os itens continuem somando o total.
+pub fn calcular_valores_com_deducoes_percentuais_e_arredondamento(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    dias_trabalhados: i32,
+    mut deducoes: Vec<Deducao>,
+    percentuais: &[(String, f64)],
+    base: BaseDeducao,
+    arredondamento: ArredondamentoDeducoes,
+) -> ResultadoCalculo {
+    let custo_transporte =
+        calcular_transporte_simples(taxa_transporte, dias_trabalhados, VIAGENS_POR_DIA_PADRAO);
+    let base = match base {
+        BaseDeducao::Total => taxa_fixa + custo_transporte,
+        BaseDeducao::ApenasTaxaFixa => taxa_fixa,
+    };
+
+    let mut deducoes_percentuais: Vec<Deducao> = percentuais
+        .iter()
+        .map(|(descricao, percentual)| Deducao {
+            valor: base.mul_fracao(percentual / 100.0),
+            descricao: descricao.clone(),
+        })
+        .collect();
+
+    if arredondamento == ArredondamentoDeducoes::NaSoma {
+        let soma: f64 = percentuais.iter().map(|(_, percentual)| percentual).sum();
+        let total = base.mul_fracao(soma / 100.0);
+        let arredondado = deducoes_percentuais
+            .iter()
+            .fold(Money::ZERO, |acc, d| acc + d.valor);
+        if let Some(ultima) = deducoes_percentuais.last_mut() {
+            ultima.valor = ultima.valor + (total - arredondado);
+        }
+    }
+
+    deducoes.extend(deducoes_percentuais);
+    calcular_valores(taxa_fixa, taxa_transporte, dias_trabalhados, deducoes)
+}
+
+/// Conveniência para quem só tem o total das deduções: vira uma única
+/// dedução sem descrição (ou nenhuma, se o total for zero).
+pub fn calcular_valores_com_deducao_total(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    dias_trabalhados: i32,
+    deducoes_total: Money,
+) -> ResultadoCalculo {
+    let deducoes = if deducoes_total == Money::ZERO {
+        Vec::new()
+    } else {
+        vec![Deducao {
+            valor: deducoes_total,
+            descricao: String::new(),
+        }]
+    };
+
+    calcular_valores(taxa_fixa, taxa_transporte, dias_trabalhados, deducoes)
+}
+
+/// Como `calcular_valores`, com a taxa fixa dividida em componentes
+/// (salário base, gratificação, ...). A taxa fixa é a soma deles, e os
+/// componentes ficam no resultado para o detalhamento do holerite.
+pub fn calcular_valores_com_componentes_fixos(
+    componentes_fixos: Vec<(String, Money)>,
+    taxa_transporte: Money,
+    dias_trabalhados: i32,
+    deducoes: Vec<Deducao>,
+) -> ResultadoCalculo {
+    let taxa_fixa = componentes_fixos.iter().map(|(_, valor)| valor).sum();
+
+    let mut resultado = calcular_valores(taxa_fixa, taxa_transporte, dias_trabalhados, deducoes);
+    resultado.componentes_fixos = componentes_fixos;
+    resultado
+}
+
+/// Como `calcular_valores`, mas com dias fracionários (18.5 para meio
+/// período num dos dias). O transporte usa o valor fracionário,
+/// arredondado ao centavo com `mul_fracao`; `dias_trabalhados` guarda os
+/// dias completos e `dias_trabalhados_fracionarios` o valor informado.
+pub fn calcular_valores_fracionarios(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    dias_trabalhados: f64,
+    deducoes: Vec<Deducao>,
+) -> ResultadoCalculo {
+    let mut resultado = calcular_valores(
+        taxa_fixa,
+        taxa_transporte,
+        dias_trabalhados.trunc() as i32,
+        deducoes,
+    );
+
+    let custo_transporte =
+        taxa_transporte.mul_fracao(dias_trabalhados * resultado.viagens_por_dia as f64);
+    resultado.pagamento_final =
+        resultado.pagamento_final - resultado.custo_transporte + custo_transporte;
+    resultado.custo_transporte = custo_transporte;
+    resultado.dias_trabalhados_fracionarios = dias_trabalhados;
+    resultado
+}
+
+/// Como `calcular_valores`, com o pagamento final garantido em pelo
+/// menos `piso` (veja `ResultadoCalculo::aplicar_piso`).
+pub fn calcular_valores_com_piso(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    dias_trabalhados: i32,
+    deducoes: Vec<Deducao>,
+    piso: Option<Money>,
+) -> ResultadoCalculo {
+    let mut resultado = calcular_valores(taxa_fixa, taxa_transporte, dias_trabalhados, deducoes);
+    resultado.aplicar_piso(piso);
+    resultado
+}
+
+/// Como `calcular_valores`, com meios-dias informados à parte: cada meio
+/// expediente conta uma única viagem (só a ida ou só a volta), então o
+/// transporte é `taxa_transporte × (dias_inteiros × 2 + meios_dias)`.
+pub fn calcular_valores_com_meios_dias(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    dias_inteiros: i32,
+    meios_dias: i32,
+    deducoes: Vec<Deducao>,
+) -> ResultadoCalculo {
+    let mut resultado = calcular_valores(taxa_fixa, taxa_transporte, dias_inteiros, deducoes);
+
+    let custo_meios_dias = calcular_transporte_simples(taxa_transporte, meios_dias, 1);
+    resultado.custo_transporte = resultado.custo_transporte + custo_meios_dias;
+    resultado.dias_trabalhados_fracionarios = dias_inteiros as f64 + meios_dias as f64 / 2.0;
+    resultado.meios_dias = meios_dias;
+    resultado.pagamento_final = resultado.pagamento_final + custo_meios_dias;
+    resultado
+}
+
+pub fn calcular_valores_com_dias_uteis(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    dias_uteis_mes: i32,
+    feriados_deduzidos: i32,
+    deducoes: Vec<Deducao>,
+) -> ResultadoCalculo {
+    calcular_com_dias_uteis(
+        taxa_fixa,
+        taxa_transporte,
+        dias_uteis_mes,
+        feriados_deduzidos,
+        VIAGENS_POR_DIA_PADRAO,
+        deducoes,
+    )
+}
+
+fn calcular_com_dias_uteis(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    dias_uteis_mes: i32,
+    feriados_deduzidos: i32,
+    viagens_por_dia: i32,
+    deducoes: Vec<Deducao>,
+) -> ResultadoCalculo {
+    let dias_trabalhados = (dias_uteis_mes - feriados_deduzidos).max(0);
+
+    let mut resultado = calcular_valores_com_viagens(
+        taxa_fixa,
+        taxa_transporte,
+        dias_trabalhados,
+        viagens_por_dia,
+        deducoes,
+    );
+
+    let calendario = InformacaoCalendario {
+        mes: None,
+        ano: None,
+        nome_mes: None,
+        dias_uteis_mes,
+        feriados_deduzidos,
+        feriados_informados: feriados_deduzidos,
+        dias_trabalhados,
+        feriados: Vec::new(),
+    };
+
+    resultado.calendario = Some(calendario);
+    resultado
+}
+
+pub fn calcular_valores_com_calendario(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    mes: u32,
+    ano: i32,
+    feriados_deduzidos: i32,
+    deducoes: Vec<Deducao>,
+) -> Result<ResultadoCalculo, ErroCalculo> {
+    calcular_valores_com_calendario_e_viagens(
+        taxa_fixa,
+        taxa_transporte,
+        mes,
+        ano,
+        feriados_deduzidos,
+        VIAGENS_POR_DIA_PADRAO,
+        deducoes,
+    )
+}
+
+/// Como `calcular_valores_com_calendario`, com o número de viagens por dia
+/// informado em vez das 2 viagens padrão.
+pub fn calcular_valores_com_calendario_e_viagens(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    mes: u32,
+    ano: i32,
+    feriados_deduzidos: i32,
+    viagens_por_dia: i32,
+    deducoes: Vec<Deducao>,
+) -> Result<ResultadoCalculo, ErroCalculo> {
+    let dias_uteis_mes = contar_dias_uteis(mes, ano)?;
+
+    let mut resultado = calcular_com_dias_uteis(
+        taxa_fixa,
+        taxa_transporte,
+        dias_uteis_mes,
+        feriados_deduzidos,
+        viagens_por_dia,
+        deducoes,
+    );
+
+    if let Some(calendario) = &mut resultado.calendario {
+        calendario.mes = Some(mes);
+        calendario.ano = Some(ano);
+        calendario.nome_mes = Some(obter_nome_mes(mes));
+    }
+    Ok(resultado)
+}
+
+/// Como `calcular_valores_com_calendario`, com as datas dos feriados em
+/// vez da quantidade. Só são deduzidas as datas do mês que caem em dia
+/// útil; datas repetidas contam uma vez. O calendário do resultado lista
+/// os feriados do mês (com o nome, quando nacional) e guarda quantas
+/// datas distintas foram informadas.
+pub fn calcular_valores_com_datas_feriados(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    mes: u32,
+    ano: i32,
+    feriados: &[NaiveDate],
+    deducoes: Vec<Deducao>,
+) -> Result<ResultadoCalculo, ErroCalculo> {
+    calcular_valores_com_datas_feriados_e_jornada(
+        taxa_fixa,
+        taxa_transporte,
+        mes,
+        ano,
+        feriados,
+        &JORNADA_PADRAO,
+        deducoes,
+    )
+}
+
+/// Como `calcular_valores_com_datas_feriados`, com os dias de trabalho da
+/// semana em `dias_trabalho` (folga na segunda, sábado trabalhado, ...).
+/// Os dias úteis do mês seguem essa jornada e um feriado só é deduzido
+/// quando cai num desses dias, para que o feriado na folga não seja
+/// descontado duas vezes. Datas repetidas ou de outro mês não contam.
+pub fn calcular_valores_com_datas_feriados_e_jornada(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    mes: u32,
+    ano: i32,
+    feriados: &[NaiveDate],
+    dias_trabalho: &[Weekday],
+    deducoes: Vec<Deducao>,
+) -> Result<ResultadoCalculo, ErroCalculo> {
+    let dias_uteis = listar_dias_uteis_com_jornada(mes, ano, dias_trabalho)?;
+
+    let mut datas = feriados.to_vec();
+    datas.sort();
+    datas.dedup();
+
+    let feriados_do_mes: Vec<FeriadoCalendario> = datas
+        .iter()
+        .filter(|data| (data.month(), data.year()) == (mes, ano))
+        .map(|&data| FeriadoCalendario {
+            data,
+            nome: nome_feriado(data).map(str::to_string),
+            deduzido: dias_uteis.contains(&data),
+        })
+        .collect();
+    let feriados_deduzidos = feriados_do_mes.iter().filter(|f| f.deduzido).count() as i32;
+
+    let mut resultado = calcular_com_dias_uteis(
+        taxa_fixa,
+        taxa_transporte,
+        dias_uteis.len() as i32,
+        feriados_deduzidos,
+        VIAGENS_POR_DIA_PADRAO,
+        deducoes,
+    );
+
+    if let Some(calendario) = &mut resultado.calendario {
+        calendario.mes = Some(mes);
+        calendario.ano = Some(ano);
+        calendario.nome_mes = Some(obter_nome_mes(mes));
+        calendario.feriados = feriados_do_mes;
+        calendario.feriados_informados = datas.len() as i32;
+    }
+    Ok(resultado)
+}
+
+/// Como `calcular_valores_com_calendario`, deduzindo os feriados nacionais
+/// do mês em vez de uma quantidade informada. O calendário do resultado
+/// lista cada feriado do mês com o nome; os de fim de semana ficam
+/// marcados como não deduzidos.
+pub fn calcular_valores_com_feriados_nacionais(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    mes: u32,
+    ano: i32,
+    deducoes: Vec<Deducao>,
+) -> Result<ResultadoCalculo, ErroCalculo> {
+    let feriados: Vec<NaiveDate> = feriados::feriados_nacionais(ano)
+        .into_iter()
+        .filter(|data| data.month() == mes)
+        .collect();
+
+    calcular_valores_com_datas_feriados(taxa_fixa, taxa_transporte, mes, ano, &feriados, deducoes)
+}
+
+/// Como `calcular_valores_com_calendario`, mas prorateado pelos dias úteis
+/// de `inicio` a `fim`. No calendário, `dias_uteis_mes` guarda os dias úteis
+/// do período; mês e ano só são preenchidos quando o período cabe num mês.
+pub fn calcular_valores_com_periodo(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    inicio: NaiveDate,
+    fim: NaiveDate,
+    feriados_deduzidos: i32,
+    deducoes: Vec<Deducao>,
+) -> Result<ResultadoCalculo, ErroCalculo> {
+    let dias_uteis_periodo = contar_dias_uteis_periodo(inicio, fim)?;
+
+    let mut resultado = calcular_com_dias_uteis(
+        taxa_fixa,
+        taxa_transporte,
+        dias_uteis_periodo,
+        feriados_deduzidos,
+        VIAGENS_POR_DIA_PADRAO,
+        deducoes,
+    );
+
+    let mesmo_mes = (inicio.month(), inicio.year()) == (fim.month(), fim.year());
+    if let (true, Some(calendario)) = (mesmo_mes, &mut resultado.calendario) {
+        calendario.mes = Some(inicio.month());
+        calendario.ano = Some(inicio.year());
+        calendario.nome_mes = Some(obter_nome_mes(inicio.month()));
+    }
+    Ok(resultado)
+}
+
+/// Como `calcular_valores`, com as datas trabalhadas em vez da quantidade,
+/// como as `datas_trabalhadas` de `processar_ponto`. Cada data conta um
+/// dia, mesmo que apareça mais de uma vez.
+pub fn calcular_por_datas(
+    taxa_fixa: Money,
+    taxa_transporte: Money,
+    datas: &[NaiveDate],
+    deducoes: Vec<Deducao>,
+) -> ResultadoCalculo {
+    let mut datas = datas.to_vec();
+    datas.sort();
+    datas.dedup();
+
+    calcular_valores(taxa_fixa, taxa_transporte, datas.len() as i32, deducoes)
+}
+
+// Parâmetros de um cálculo com calendário, para reaproveitar e variar.
+// O nome identifica o funcionário no cálculo em lote e pode ficar vazio.
+#[derive(Debug, Clone)]
+pub struct EntradaCalculo {
+    pub nome: String,
+    pub taxa_fixa: Money,
+    pub taxa_transporte: Money,
+    pub mes: u32,
+    pub ano: i32,
+    pub feriados_deduzidos: i32,
+    pub deducoes: Vec<Deducao>,
+}
+
+impl EntradaCalculo {
+    pub fn calcular(&self) -> Result<ResultadoCalculo, ErroCalculo> {
+        calcular_valores_com_calendario(
+            self.taxa_fixa,
+            self.taxa_transporte,
+            self.mes,
+            self.ano,
+            self.feriados_deduzidos,
+            self.deducoes.clone(),
+        )
+    }
+}
+
+pub fn obter_valor_numerico(prompt: &str) -> Money {
+    obter_valor_numerico_com_padrao(prompt, None)
+}
+
+/// Como `obter_valor_numerico`, mas com um valor sugerido no prompt que
+/// é usado quando a entrada fica em branco.
+pub fn obter_valor_numerico_com_padrao(prompt: &str, padrao: Option<Money>) -> Money {
     loop {
-        println!("{}", prompt);
+        match padrao {
+            Some(padrao) => println!("{} [{}]", prompt, padrao),
+            None => println!("{}", prompt),
+        }
         let mut input = String::new();
         std::io::stdin()
             .read_line(&mut input)
             .expect("Falha ao ler entrada");
 
+        if let (Some(padrao), true) = (padrao, input.trim().is_empty()) {
+            return padrao;
+        }
         match Money::parse(&input) {
             Ok(valor) => return valor,
             Err(_) => println!("Erro: Por favor, digite um valor numérico válido."),
@@ -274,7 +1536,7 @@ pub fn obter_mes() -> u32 {
             .expect("Falha ao ler entrada");
 
         match input.trim().parse::<u32>() {
-            Ok(mes) if mes >= 1 && mes <= 12 => return mes,
+            Ok(mes) if (1..=12).contains(&mes) => return mes,
             Ok(_) => println!("Erro: Mês deve estar entre 1 e 12."),
             Err(_) => println!("Erro: Por favor, digite um número válido."),
         }
@@ -290,7 +1552,7 @@ pub fn obter_ano() -> i32 {
             .expect("Falha ao ler entrada");
 
         match input.trim().parse::<i32>() {
-            Ok(ano) if ano >= 1900 && ano <= 2100 => return ano,
+            Ok(ano) if (1900..=2100).contains(&ano) => return ano,
             Ok(_) => println!("Erro: Ano deve estar entre 1900 e 2100."),
             Err(_) => println!("Erro: Por favor, digite um ano válido."),
         }
@@ -313,6 +1575,88 @@ pub fn obter_feriados() -> i32 {
     }
 }
 
+/// Lista os feriados nacionais do mês que caem em dia útil, todos
+/// marcados, e deixa o usuário marcar ou desmarcar cada um pelo número
+/// até confirmar com uma linha em branco (ou fim da entrada). Devolve as
+/// datas marcadas. A entrada e a saída são injetáveis para que a
+/// interação possa ser testada; falhas de leitura ou escrita viram erro.
+pub fn confirmar_feriados_nacionais<R: std::io::BufRead, W: std::io::Write>(
+    mes: u32,
+    ano: i32,
+    entrada: &mut R,
+    saida: &mut W,
+) -> Result<Vec<NaiveDate>, ErroCalculo> {
+    let erro_io = |erro: std::io::Error| ErroCalculo::EntradaSaida(erro.to_string());
+
+    let nacionais = feriados::feriados_nacionais(ano);
+    let feriados: Vec<NaiveDate> = listar_dias_uteis(mes, ano)?
+        .into_iter()
+        .filter(|data| nacionais.contains(data))
+        .collect();
+    if feriados.is_empty() {
+        writeln!(
+            saida,
+            "Nenhum feriado nacional em dia útil em {} de {}.",
+            obter_nome_mes(mes),
+            ano
+        )
+        .map_err(erro_io)?;
+        return Ok(Vec::new());
+    }
+
+    let mut marcados = vec![true; feriados.len()];
+    loop {
+        writeln!(
+            saida,
+            "Feriados nacionais em {} de {} (os marcados são deduzidos):",
+            obter_nome_mes(mes),
+            ano
+        )
+        .map_err(erro_io)?;
+        for (indice, data) in feriados.iter().enumerate() {
+            writeln!(
+                saida,
+                "  [{}] {}. {} - {}",
+                if marcados[indice] { 'x' } else { ' ' },
+                indice + 1,
+                data.format("%d/%m"),
+                feriados::nome_feriado(*data).unwrap_or_default()
+            )
+            .map_err(erro_io)?;
+        }
+        writeln!(
+            saida,
+            "Digite o número de um feriado para marcar/desmarcar ou deixe em branco para confirmar:"
+        )
+        .map_err(erro_io)?;
+
+        let mut input = String::new();
+        let lidos = entrada.read_line(&mut input).map_err(erro_io)?;
+        if lidos == 0 || input.trim().is_empty() {
+            break;
+        }
+
+        match input.trim().parse::<usize>() {
+            Ok(numero) if (1..=feriados.len()).contains(&numero) => {
+                marcados[numero - 1] = !marcados[numero - 1];
+            }
+            _ => writeln!(
+                saida,
+                "Erro: Digite um número entre 1 e {}.",
+                feriados.len()
+            )
+            .map_err(erro_io)?,
+        }
+    }
+
+    Ok(feriados
+        .into_iter()
+        .zip(marcados)
+        .filter(|&(_, marcado)| marcado)
+        .map(|(data, _)| data)
+        .collect())
+}
+
 pub fn obter_deducoes() -> Vec<Deducao> {
     let mut deducoes = Vec::new();
 
@@ -356,82 +1700,124 @@ pub fn obter_deducoes() -> Vec<Deducao> {
     deducoes
 }
 
+// Opções de exibição da CLI
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OpcoesCli {
+    pub cores: bool, // rótulos em negrito e pagamento final em verde/vermelho
+    pub brl: bool,   // valores no padrão brasileiro: "R$ 1.234,56"
+}
+
 pub fn calcular_pagamento() {
+    if let Err(erro) = calcular_pagamento_com_opcoes(OpcoesCli::default()) {
+        eprintln!("Erro no cálculo: {}", erro);
+    }
+}
+
+/// Como `calcular_pagamento`, com as opções de exibição da CLI. Erros do
+/// cálculo são devolvidos para quem chamou em vez de impressos. As taxas
+/// de `~/.calculadora.toml`, se houver, são sugeridas nos prompts.
+pub fn calcular_pagamento_com_opcoes(opcoes: OpcoesCli) -> Result<(), ErroCalculo> {
+    // Variáveis CALC_* presentes valem no lugar das perguntas
+    let ambiente = ler_config_parcial(&std::env::vars().collect())?;
+    let padrao = ConfiguracaoPadrao::carregar_do_usuario()?;
+    calcular_pagamento_com_padrao(&ambiente, &padrao, opcoes)
+}
+
+/// Como `calcular_pagamento_com_opcoes`, perguntando só o que não veio
+/// em `argumentos`; o ambiente e o arquivo de configuração não são lidos.
+/// No modo não-interativo nada é lido do stdin.
+pub fn calcular_pagamento_com_argumentos(
+    argumentos: &ArgumentosCli,
+    opcoes: OpcoesCli,
+) -> Result<(), ErroCalculo> {
+    calcular_pagamento_com_padrao(argumentos, &ConfiguracaoPadrao::default(), opcoes)
+}
+
+/// Como `calcular_pagamento_com_argumentos`, com as taxas de `padrao`
+/// sugeridas nos prompts; digitar um valor substitui a sugestão.
+pub fn calcular_pagamento_com_padrao(
+    argumentos: &ArgumentosCli,
+    padrao: &ConfiguracaoPadrao,
+    opcoes: OpcoesCli,
+) -> Result<(), ErroCalculo> {
+    if let Some(entrada) = argumentos.para_entrada() {
+        print!("{}", resumo_pagamento(&entrada, opcoes)?);
+        return Ok(());
+    }
+
     println!("=== CALCULADORA DE PAGAMENTO ===\n");
 
     // Coleta de dados
-    let taxa_fixa = obter_valor_numerico("Digite a taxa fixa (R$):");
-    let taxa_transporte = obter_valor_numerico("Digite a taxa de transporte por viagem (R$):");
+    let taxa_fixa = argumentos.taxa_fixa.unwrap_or_else(|| {
+        obter_valor_numerico_com_padrao("Digite a taxa fixa (R$):", padrao.taxa_fixa)
+    });
+    let taxa_transporte = argumentos.taxa_transporte.unwrap_or_else(|| {
+        obter_valor_numerico_com_padrao(
+            "Digite a taxa de transporte por viagem (R$):",
+            padrao.taxa_transporte,
+        )
+    });
 
     // Cálculo baseado em calendário
-    let mes = obter_mes();
-    let ano = obter_ano();
-    let feriados = obter_feriados();
-    let deducoes = obter_deducoes();
+    let mes = argumentos.mes.unwrap_or_else(obter_mes);
+    let ano = argumentos.ano.unwrap_or_else(obter_ano);
+    // Sem a quantidade de feriados, os feriados nacionais do mês são
+    // confirmados um a um
+    let datas_feriados = match argumentos.feriados {
+        Some(_) => None,
+        None => Some(confirmar_feriados_nacionais(
+            mes,
+            ano,
+            &mut std::io::stdin().lock(),
+            &mut std::io::stdout(),
+        )?),
+    };
+    let deducoes = argumentos.deducoes.clone().unwrap_or_else(obter_deducoes);
 
     // Cálculo com calendário
-    let resultado = match calcular_valores_com_calendario(
-        taxa_fixa,
-        taxa_transporte,
-        mes,
-        ano,
-        feriados,
-        deducoes,
-    ) {
-        Ok(resultado) => resultado,
-        Err(erro) => {
-            println!("Erro no cálculo: {}", erro);
-            return;
-        }
+    let resultado = match datas_feriados {
+        Some(datas) => calcular_valores_com_datas_feriados_e_jornada(
+            taxa_fixa,
+            taxa_transporte,
+            mes,
+            ano,
+            &datas,
+            &JORNADA_PADRAO,
+            deducoes,
+        )?,
+        None => calcular_valores_com_calendario(
+            taxa_fixa,
+            taxa_transporte,
+            mes,
+            ano,
+            argumentos.feriados.unwrap_or_default(),
+            deducoes,
+        )?,
     };
 
-    // Exibição dos resultados
-    println!("\n{}", "=".repeat(40));
-    println!("RESUMO DO PAGAMENTO");
-    println!("{}", "=".repeat(40));
-    println!("Taxa fixa: R$ {}", resultado.taxa_fixa);
-
-    // Informações do calendário
-    if let Some(calendario) = &resultado.calendario {
-        println!("Mês/Ano: {} {}", calendario.nome_mes, calendario.ano);
-        println!("Dias úteis no mês: {}", calendario.dias_uteis_mes);
-        if calendario.feriados_deduzidos > 0 {
-            println!(
-                "Feriados/dias não trabalhados: {}",
-                calendario.feriados_deduzidos
-            );
-        }
-        println!("Dias trabalhados: {}", calendario.dias_trabalhados);
-    }
+    print!("\n{}", formatar_para_cli(&resultado, opcoes));
+    Ok(())
+}
 
-    println!(
-        "Taxa de transporte por viagem: R$ {}",
-        resultado.taxa_transporte
-    );
-    println!(
-        "Custo total do transporte: R$ {}",
-        resultado.custo_transporte
-    );
-    println!(
-        "  ({} dias × R$ {} × 2 viagens)",
-        resultado.dias_trabalhados, resultado.taxa_transporte
-    );
+/// Calcula a entrada e devolve o resumo que a CLI exibe, sem ler nada
+/// do stdin.
+pub fn resumo_pagamento(
+    entrada: &EntradaCalculo,
+    opcoes: OpcoesCli,
+) -> Result<String, ErroCalculo> {
+    let resultado = entrada.calcular()?;
+    Ok(formatar_para_cli(&resultado, opcoes))
+}
 
-    if !resultado.deducoes.is_empty() {
-        println!("\nDeduções:");
-        for deducao in &resultado.deducoes {
-            if deducao.descricao.is_empty() {
-                println!("  - R$ {}", deducao.valor);
-            } else {
-                println!("  - R$ {} ({})", deducao.valor, deducao.descricao);
-            }
-        }
-        println!("Total de deduções: R$ {}", resultado.deducoes_total);
+// Exibição dos resultados
+fn formatar_para_cli(resultado: &ResultadoCalculo, opcoes: OpcoesCli) -> String {
+    if opcoes.brl {
+        formatar_resultado_brl(resultado, opcoes.cores)
+    } else if opcoes.cores {
+        formatar_resultado_colorido(resultado)
+    } else {
+        formatar_resultado(resultado)
     }
-
-    println!("{}", "-".repeat(40));
-    println!("PAGAMENTO FINAL: R$ {}", resultado.pagamento_final);
-    println!("{}", "=".repeat(40));
 }
 
 #[cfg(test)]
@@ -453,22 +1839,1029 @@ mod tests {
     }
 
     #[test]
-    fn test_calculo_basico() {
-        let resultado = calcular_valores(
-            Money::from_reais(100),
-            Money::from_reais(5),
-            10,
-            vec![],
+    fn test_money_parsing_virgula_decimal() {
+        assert_eq!(Money::parse("7,50").unwrap(), Money::from_centavos(750));
+        assert_eq!(Money::parse("7,5").unwrap(), Money::from_centavos(750));
+        assert_eq!(Money::parse("-20,50").unwrap(), Money::from_centavos(-2050));
+        assert_eq!(
+            Money::parse("(20,50)").unwrap(),
+            Money::from_centavos(-2050)
         );
-
-        assert_eq!(resultado.custo_transporte, Money::from_reais(100)); // 5 * 10 * 2
-        assert_eq!(resultado.pagamento_final, Money::from_reais(200)); // 100 + 100 - 0
+        assert_eq!(Money::parse("\"7,50\"").unwrap(), Money::from_centavos(750));
+        assert_eq!(
+            Money::parse("\"R$ 7,50\"").unwrap(),
+            Money::from_centavos(750)
+        );
+        assert!(Money::parse("7,505").is_err());
     }
 
     #[test]
-    fn test_calculo_com_deducoes() {
-        let resultado = calcular_valores(
-            Money::from_reais(150),
+    fn test_money_parsing_separador_de_milhar() {
+        let esperado = Money::from_centavos(123456);
+
+        assert_eq!(Money::parse("1.234,56").unwrap(), esperado);
+        assert_eq!(Money::parse("1,234.56").unwrap(), esperado);
+        assert_eq!(
+            Money::parse("1.234.567,89").unwrap(),
+            Money::from_centavos(123456789)
+        );
+        assert_eq!(
+            Money::parse("1,234,567").unwrap(),
+            Money::from_reais(1234567)
+        );
+        assert_eq!(
+            Money::parse("-1.234,56").unwrap(),
+            Money::from_centavos(-123456)
+        );
+    }
+
+    #[test]
+    fn test_money_parsing_ponto_com_tres_digitos_e_milhar() {
+        assert_eq!(Money::parse("1.234").unwrap(), Money::from_reais(1234));
+        assert_eq!(Money::parse("123.456").unwrap(), Money::from_reais(123456));
+        // Com dois dígitos continua sendo decimal
+        assert_eq!(Money::parse("1.23").unwrap(), Money::from_centavos(123));
+    }
+
+    #[test]
+    fn test_money_parsing_separadores_invalidos() {
+        assert!(Money::parse("12.34,56").is_err());
+        assert!(Money::parse("1.2345,00").is_err());
+        assert!(Money::parse(".234,56").is_err());
+        assert!(Money::parse("1,234,56.7").is_err());
+        assert!(Money::parse("1.234,5,6").is_err());
+    }
+
+    #[test]
+    fn test_money_from_str_e_try_from() {
+        assert_eq!("7.50".parse::<Money>(), Ok(Money::from_centavos(750)));
+        assert_eq!(
+            Money::try_from("1.234,56"),
+            Ok(Money::from_centavos(123456))
+        );
+        assert_eq!(
+            "7,505".parse::<Money>(),
+            Err(ErroParseMoney::MuitasCasasDecimais)
+        );
+        assert_eq!("abc".parse::<Money>(), Err(ErroParseMoney::NumeroInvalido));
+        assert_eq!(
+            Money::try_from("x.50"),
+            Err(ErroParseMoney::ParteReaisInvalida)
+        );
+        assert_eq!(
+            Money::try_from("1.5x"),
+            Err(ErroParseMoney::ParteCentavosInvalida)
+        );
+        assert_eq!(
+            "(20".parse::<Money>(),
+            Err(ErroParseMoney::ParentesesDesbalanceados)
+        );
+        assert_eq!(
+            "\"7.50".parse::<Money>(),
+            Err(ErroParseMoney::AspasDesbalanceadas)
+        );
+        assert_eq!(
+            "R$".parse::<Money>(),
+            Err(ErroParseMoney::ValorAusenteAposSimbolo)
+        );
+    }
+
+    #[test]
+    fn test_money_parse_mensagens_em_portugues() {
+        assert_eq!(
+            Money::parse("7,505"),
+            Err(ErroParseMoney::MuitasCasasDecimais)
+        );
+        assert_eq!(
+            Money::parse("7,505").unwrap_err().to_string(),
+            "Casas decimais demais"
+        );
+        assert_eq!(
+            Money::parse("abc").unwrap_err().to_string(),
+            "Número inválido"
+        );
+        let erro: Box<dyn std::error::Error> = Box::new(ErroParseMoney::NumeroInvalido);
+        assert_eq!(erro.to_string(), "Número inválido");
+    }
+
+    #[test]
+    fn test_erro_parse_money_em_erro_calculo() {
+        fn transporte_do_mes(texto: &str, mes: u32) -> Result<Money, ErroCalculo> {
+            let taxa = Money::parse(texto)?;
+            Ok(taxa * contar_dias_uteis(mes, 2024)?)
+        }
+
+        assert_eq!(
+            transporte_do_mes("7.50", 11),
+            Ok(Money::parse("157.50").unwrap())
+        );
+        assert_eq!(
+            transporte_do_mes("7,505", 11),
+            Err(ErroCalculo::ParseMoney(ErroParseMoney::MuitasCasasDecimais))
+        );
+        assert_eq!(
+            transporte_do_mes("abc", 11).unwrap_err().to_string(),
+            "Valor monetário inválido: Número inválido"
+        );
+        assert_eq!(
+            transporte_do_mes("7.50", 13),
+            Err(ErroCalculo::MesInvalido(13))
+        );
+    }
+
+    #[test]
+    fn test_money_parse_permissivo_sinal_posfixado() {
+        assert_eq!(
+            Money::parse_permissivo("20,50-"),
+            Ok(Money::from_centavos(-2050))
+        );
+        assert_eq!(Money::parse_permissivo("100CR"), Ok(Money::from_reais(100)));
+        assert_eq!(Money::parse_permissivo("50DB"), Ok(Money::from_reais(-50)));
+        assert_eq!(
+            Money::parse_permissivo(" 1.234,56 db "),
+            Ok(Money::from_centavos(-123456))
+        );
+        // Sem sufixo, igual ao modo estrito
+        assert_eq!(
+            Money::parse_permissivo("-7,50"),
+            Ok(Money::from_centavos(-750))
+        );
+    }
+
+    #[test]
+    fn test_money_parse_permissivo_invalidos() {
+        assert_eq!(
+            Money::parse_permissivo("-20,50-"),
+            Err(ErroParseMoney::SinalDuplicado)
+        );
+        assert_eq!(
+            Money::parse_permissivo("(50)DB"),
+            Err(ErroParseMoney::SinalDuplicado)
+        );
+        assert!(Money::parse_permissivo("abcDB").is_err());
+        for so_sufixo in ["-", "CR", "DB", " db "] {
+            assert_eq!(
+                Money::parse_permissivo(so_sufixo),
+                Err(ErroParseMoney::NumeroInvalido)
+            );
+        }
+        // Vazio continua zero, como no modo estrito
+        assert_eq!(Money::parse_permissivo(""), Ok(Money::ZERO));
+        // O modo estrito não muda
+        assert!(Money::parse("20,50-").is_err());
+        assert!(Money::parse("100CR").is_err());
+        assert!(Money::parse("50DB").is_err());
+    }
+
+    #[test]
+    fn test_money_parsing_notacao_contabil() {
+        assert_eq!(
+            Money::parse("(20.50)").unwrap(),
+            Money::from_centavos(-2050)
+        );
+        assert_eq!(Money::parse("(100)").unwrap(), Money::from_centavos(-10000));
+        assert_eq!(
+            Money::parse(" ( 7.5 ) ").unwrap(),
+            Money::from_centavos(-750)
+        );
+
+        assert!(Money::parse("(20.50").is_err());
+        assert!(Money::parse("20.50)").is_err());
+        assert!(Money::parse("()").is_err());
+        assert!(Money::parse("((20))").is_err());
+        assert!(Money::parse("(-20)").is_err());
+    }
+
+    #[test]
+    fn test_money_parsing_entre_aspas() {
+        assert_eq!(Money::parse("\"7.50\"").unwrap(), Money::from_centavos(750));
+        assert_eq!(Money::parse("'7.50'").unwrap(), Money::from_centavos(750));
+        assert_eq!(
+            Money::parse(" \" -20.50 \" ").unwrap(),
+            Money::from_centavos(-2050)
+        );
+        assert_eq!(
+            Money::parse("'(100)'").unwrap(),
+            Money::from_centavos(-10000)
+        );
+        assert_eq!(Money::parse("\"\"").unwrap(), Money::ZERO);
+
+        assert!(Money::parse("\"7.50").is_err());
+        assert!(Money::parse("7.50'").is_err());
+        assert!(Money::parse("\"7.50'").is_err());
+        assert!(Money::parse("\"7\"50\"").is_err());
+        assert!(Money::parse("'\"7.50\"'").is_err());
+        assert!(Money::parse("\"").is_err());
+    }
+
+    #[test]
+    fn test_money_parsing_prefixo_reais() {
+        assert_eq!(Money::parse("R$ 7.50").unwrap(), Money::from_centavos(750));
+        assert_eq!(Money::parse("R$7.50").unwrap(), Money::from_centavos(750));
+        assert_eq!(
+            Money::parse("\"R$ 7.50\"").unwrap(),
+            Money::from_centavos(750)
+        );
+        assert_eq!(
+            Money::parse("'R$ -20.50'").unwrap(),
+            Money::from_centavos(-2050)
+        );
+        assert_eq!(
+            Money::parse("R$ (100)").unwrap(),
+            Money::from_centavos(-10000)
+        );
+
+        assert!(Money::parse("R$").is_err());
+        assert!(Money::parse("\"R$\"").is_err());
+        assert!(Money::parse("7.50 R$").is_err());
+        assert!(Money::parse("R$ R$ 7.50").is_err());
+    }
+
+    #[test]
+    fn test_money_sum() {
+        let valores = [
+            Money::parse("10.50").unwrap(),
+            Money::parse("-0.75").unwrap(),
+            Money::from_centavos(1),
+        ];
+
+        assert_eq!(valores.iter().sum::<Money>(), Money::parse("9.76").unwrap());
+        assert_eq!(
+            valores.into_iter().sum::<Money>(),
+            Money::parse("9.76").unwrap()
+        );
+        assert_eq!(std::iter::empty::<Money>().sum::<Money>(), Money::ZERO);
+    }
+
+    #[test]
+    fn test_money_mul_fracao() {
+        let taxa_fixa = Money::from_reais(1500);
+
+        // 8% de desconto
+        assert_eq!(taxa_fixa.mul_fracao(0.08), Money::from_reais(120));
+        assert_eq!(taxa_fixa.mul_fracao(1.0), taxa_fixa);
+        assert_eq!(taxa_fixa.mul_fracao(0.0), Money::ZERO);
+        // 0.10 × 1.15 = 0.115; em f64 daria 11.4999... centavos
+        assert_eq!(
+            Money::from_centavos(10).mul_fracao(1.15),
+            Money::from_centavos(12)
+        );
+    }
+
+    #[test]
+    fn test_money_mul_fracao_meio_centavo() {
+        // 10.01 × 0.5 = 5.005 → 5.01
+        assert_eq!(
+            Money::parse("10.01").unwrap().mul_fracao(0.5),
+            Money::parse("5.01").unwrap()
+        );
+        // Negativos arredondam para longe do zero
+        assert_eq!(
+            Money::parse("-10.01").unwrap().mul_fracao(0.5),
+            Money::parse("-5.01").unwrap()
+        );
+        assert_eq!(
+            Money::parse("10.01").unwrap().mul_fracao(-0.5),
+            Money::parse("-5.01").unwrap()
+        );
+        // 0.01 × 0.333 = 0.00333 → zero, sem fração de centavo
+        assert_eq!(Money::from_centavos(1).mul_fracao(0.333), Money::ZERO);
+    }
+
+    #[test]
+    fn test_money_dividir() {
+        let centavos = |parcelas: Vec<Money>| -> Vec<i64> {
+            parcelas.into_iter().map(Money::to_centavos).collect()
+        };
+
+        assert_eq!(centavos(Money::from_reais(10).dividir(3)), [334, 333, 333]);
+        assert_eq!(centavos(Money::from_reais(10).dividir(4)), [250; 4]);
+        assert_eq!(centavos(Money::from_centavos(2).dividir(3)), [1, 1, 0]);
+        assert_eq!(centavos(Money::from_reais(10).dividir(1)), [1000]);
+    }
+
+    #[test]
+    fn test_money_dividir_negativo_e_soma_exata() {
+        let parcelas = Money::from_reais(-10).dividir(3);
+
+        assert_eq!(
+            parcelas,
+            [
+                Money::parse("-3.34").unwrap(),
+                Money::parse("-3.33").unwrap(),
+                Money::parse("-3.33").unwrap(),
+            ]
+        );
+        for n in 1..=12 {
+            let valor = Money::parse("1234.57").unwrap();
+            assert_eq!(valor.dividir(n).iter().sum::<Money>(), valor, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_money_dividir_por_zero_ou_negativo() {
+        assert!(Money::from_reais(10).dividir(0).is_empty());
+        assert!(Money::from_reais(10).dividir(-2).is_empty());
+    }
+
+    #[test]
+    fn test_money_from_reais_centavos() {
+        assert_eq!(
+            Money::from_reais_centavos(123, 45),
+            Ok(Money::parse("123.45").unwrap())
+        );
+        assert_eq!(
+            Money::from_reais_centavos(-7, 50),
+            Ok(Money::parse("-7.50").unwrap())
+        );
+        assert_eq!(
+            Money::from_reais_centavos(0, 5),
+            Ok(Money::from_centavos(5))
+        );
+        assert_eq!(Money::from_reais_centavos(10, 0), Ok(Money::from_reais(10)));
+    }
+
+    #[test]
+    fn test_money_from_reais_centavos_invalidos() {
+        assert_eq!(
+            Money::from_reais_centavos(1, 100),
+            Err(ErroCalculo::CentavosInvalidos(100))
+        );
+        assert_eq!(
+            Money::from_reais_centavos(-1, 255),
+            Err(ErroCalculo::CentavosInvalidos(255))
+        );
+    }
+
+    fn data(ano: i32, mes: u32, dia: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(ano, mes, dia).unwrap()
+    }
+
+    #[test]
+    fn test_contar_dias_uteis_com_jornada() {
+        use Weekday::*;
+
+        // Novembro de 2024: 21 dias de segunda a sexta e 5 sábados (2, 9, 16, 23, 30)
+        assert_eq!(
+            contar_dias_uteis_com_jornada(11, 2024, &[Mon, Tue, Wed, Thu, Fri, Sat]),
+            Ok(26)
+        );
+        // Folga às segundas: 4, 11, 18 e 25
+        assert_eq!(
+            contar_dias_uteis_com_jornada(11, 2024, &[Tue, Wed, Thu, Fri]),
+            Ok(17)
+        );
+        assert_eq!(
+            contar_dias_uteis_com_jornada(11, 2024, &[Mon, Tue, Wed, Thu, Fri]),
+            contar_dias_uteis(11, 2024)
+        );
+        assert_eq!(contar_dias_uteis_com_jornada(11, 2024, &[]), Ok(0));
+        assert_eq!(
+            contar_dias_uteis_com_jornada(13, 2024, &[Sat]),
+            Err(ErroCalculo::MesInvalido(13))
+        );
+    }
+
+    #[test]
+    fn test_dias_nao_trabalhados_com_enforcado() {
+        // Junho de 2025: Corpus Christi (19, quinta) emenda a sexta 20
+        let feriados = feriados_nacionais(2025);
+
+        assert_eq!(
+            dias_nao_trabalhados(6, 2025, &feriados, PoliticaEmenda::SemEmenda),
+            Ok(1)
+        );
+        assert_eq!(
+            dias_nao_trabalhados(6, 2025, &feriados, PoliticaEmenda::Enforcado),
+            Ok(2)
+        );
+        // Terça 01/10/2024 emendaria a segunda 30/09, que é de outro mês
+        assert_eq!(
+            dias_nao_trabalhados(10, 2024, &[data(2024, 10, 1)], PoliticaEmenda::Enforcado),
+            Ok(1)
+        );
+        assert_eq!(
+            dias_nao_trabalhados(9, 2024, &[data(2024, 10, 1)], PoliticaEmenda::Enforcado),
+            Ok(1)
+        );
+        assert_eq!(
+            dias_nao_trabalhados(13, 2024, &[], PoliticaEmenda::Enforcado),
+            Err(ErroCalculo::MesInvalido(13))
+        );
+    }
+
+    #[test]
+    fn test_listar_dias_uteis() {
+        let dias = listar_dias_uteis(11, 2024).unwrap();
+
+        assert_eq!(dias.len(), 21);
+        assert_eq!(dias[0], data(2024, 11, 1)); // sexta
+        assert_eq!(dias[1], data(2024, 11, 4)); // segunda
+        assert_eq!(dias[20], data(2024, 11, 29));
+        assert!(dias.windows(2).all(|par| par[0] < par[1]));
+        assert!(dias
+            .iter()
+            .all(|d| !matches!(d.weekday(), Weekday::Sat | Weekday::Sun)));
+    }
+
+    #[test]
+    fn test_listar_dias_uteis_ultimo_dia_do_mes() {
+        // 29/02/2024 é quinta; 31/12/2024 é terça
+        assert_eq!(
+            listar_dias_uteis(2, 2024).unwrap().last(),
+            Some(&data(2024, 2, 29))
+        );
+        assert_eq!(
+            listar_dias_uteis(12, 2024).unwrap().last(),
+            Some(&data(2024, 12, 31))
+        );
+        // 28/02/2025 é sexta
+        assert_eq!(
+            listar_dias_uteis(2, 2025).unwrap().last(),
+            Some(&data(2025, 2, 28))
+        );
+        for mes in 1..=12 {
+            assert_eq!(
+                listar_dias_uteis(mes, 2024).unwrap().len() as i32,
+                contar_dias_uteis(mes, 2024).unwrap()
+            );
+        }
+        assert_eq!(listar_dias_uteis(0, 2024), Err(ErroCalculo::MesInvalido(0)));
+    }
+
+    #[test]
+    fn test_pre_computar_dias_uteis() {
+        let dias_uteis = pre_computar_dias_uteis(2024, 2025).unwrap();
+
+        assert_eq!(dias_uteis.len(), 24);
+        assert_eq!(dias_uteis[&(2, 2024)], 21); // bissexto
+        assert_eq!(dias_uteis[&(11, 2024)], 21);
+        assert_eq!(dias_uteis[&(2, 2025)], 20);
+        assert_eq!(dias_uteis[&(12, 2025)], 23);
+        for (&(mes, ano), &dias) in &dias_uteis {
+            assert_eq!(contar_dias_uteis(mes, ano), Ok(dias));
+        }
+    }
+
+    #[test]
+    fn test_pre_computar_dias_uteis_faixa_invalida() {
+        assert_eq!(
+            pre_computar_dias_uteis(2025, 2024),
+            Err(ErroCalculo::PeriodoInvalido)
+        );
+        assert_eq!(
+            pre_computar_dias_uteis(2099, 2101),
+            Err(ErroCalculo::AnoInvalido(2101))
+        );
+        assert_eq!(pre_computar_dias_uteis(2024, 2024).unwrap().len(), 12);
+    }
+
+    #[test]
+    fn test_dias_uteis_decorridos() {
+        // Novembro de 2024 começa numa sexta e tem 21 dias úteis
+        assert_eq!(dias_uteis_decorridos(data(2024, 11, 1), &[]), Ok(1));
+        assert_eq!(dias_uteis_decorridos(data(2024, 11, 3), &[]), Ok(1));
+        assert_eq!(dias_uteis_decorridos(data(2024, 11, 4), &[]), Ok(2));
+        assert_eq!(dias_uteis_decorridos(data(2024, 11, 18), &[]), Ok(12));
+        assert_eq!(dias_uteis_decorridos(data(2024, 11, 30), &[]), Ok(21));
+    }
+
+    #[test]
+    fn test_dias_uteis_decorridos_com_feriados() {
+        let feriados = [data(2024, 11, 15), data(2024, 11, 20), data(2024, 12, 25)];
+
+        assert_eq!(dias_uteis_decorridos(data(2024, 11, 14), &feriados), Ok(10));
+        assert_eq!(dias_uteis_decorridos(data(2024, 11, 15), &feriados), Ok(10));
+        assert_eq!(dias_uteis_decorridos(data(2024, 11, 30), &feriados), Ok(19));
+    }
+
+    #[test]
+    fn test_dias_uteis_decorridos_mais_restantes() {
+        let feriados = [data(2024, 11, 15), data(2024, 11, 20)];
+
+        for dia in 1..=30 {
+            let hoje = data(2024, 11, dia);
+            let decorridos = dias_uteis_decorridos(hoje, &feriados).unwrap();
+            let restantes = dias_uteis_restantes(hoje, &feriados).unwrap();
+            assert_eq!(decorridos + restantes, 19, "dia {}", dia);
+        }
+        assert_eq!(dias_uteis_restantes(data(2024, 11, 30), &[]), Ok(0));
+    }
+
+    #[test]
+    fn test_dias_corridos_vs_uteis() {
+        // 25/11 a 06/12 de 2024: 12 dias corridos, 10 úteis
+        let (inicio, fim) = (data(2024, 11, 25), data(2024, 12, 6));
+
+        assert_eq!(contar_dias_corridos(inicio, fim), Ok(12));
+        assert_eq!(dias_uteis_no_intervalo(inicio, fim, &[]), 10);
+
+        // Virada de ano
+        assert_eq!(
+            contar_dias_corridos(data(2024, 12, 30), data(2025, 1, 2)),
+            Ok(4)
+        );
+        // Fevereiro bissexto inteiro
+        assert_eq!(
+            contar_dias_corridos(data(2024, 2, 1), data(2024, 2, 29)),
+            Ok(29)
+        );
+        assert_eq!(contar_dias_corridos(inicio, inicio), Ok(1));
+    }
+
+    #[test]
+    fn test_contar_dias_uteis_periodo() {
+        // Admissão em 11/11/2024 (segunda): 15 dos 21 dias úteis do mês
+        assert_eq!(
+            contar_dias_uteis_periodo(data(2024, 11, 11), data(2024, 11, 30)),
+            Ok(15)
+        );
+        assert_eq!(
+            contar_dias_uteis_periodo(data(2024, 11, 1), data(2024, 11, 30)),
+            contar_dias_uteis(11, 2024)
+        );
+    }
+
+    #[test]
+    fn test_contar_dias_uteis_periodo_de_um_dia() {
+        assert_eq!(
+            contar_dias_uteis_periodo(data(2024, 11, 11), data(2024, 11, 11)),
+            Ok(1)
+        );
+        // Sábado
+        assert_eq!(
+            contar_dias_uteis_periodo(data(2024, 11, 9), data(2024, 11, 9)),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn test_contar_dias_uteis_periodo_cruzando_mes_e_ano() {
+        // 25/11 a 06/12 de 2024
+        assert_eq!(
+            contar_dias_uteis_periodo(data(2024, 11, 25), data(2024, 12, 6)),
+            Ok(10)
+        );
+        // 30/12/2024 (segunda) a 03/01/2025 (sexta)
+        assert_eq!(
+            contar_dias_uteis_periodo(data(2024, 12, 30), data(2025, 1, 3)),
+            Ok(5)
+        );
+        assert_eq!(
+            contar_dias_uteis_periodo(data(2025, 1, 3), data(2024, 12, 30)),
+            Err(ErroCalculo::PeriodoInvalido)
+        );
+    }
+
+    #[test]
+    fn test_dias_corridos_fim_antes_do_inicio() {
+        assert_eq!(
+            contar_dias_corridos(data(2024, 12, 6), data(2024, 11, 25)),
+            Err(ErroCalculo::PeriodoInvalido)
+        );
+    }
+
+    #[test]
+    fn test_arredondar_para_cima_com_centavos() {
+        let mut resultado =
+            calcular_valores(Money::parse("1000.01").unwrap(), Money::ZERO, 0, vec![]);
+
+        let ajuste = resultado.arredondar_para_cima();
+
+        assert_eq!(ajuste, Money::parse("0.99").unwrap());
+        assert_eq!(resultado.pagamento_final, Money::from_reais(1001));
+        assert_eq!(resultado.proventos[0].descricao, "Arredondamento");
+        assert_eq!(resultado.proventos_total, ajuste);
+    }
+
+    #[test]
+    fn test_arredondar_para_cima_sem_centavos() {
+        let mut resultado = calcular_valores(Money::from_reais(1000), Money::ZERO, 0, vec![]);
+
+        assert_eq!(resultado.arredondar_para_cima(), Money::ZERO);
+        assert_eq!(resultado.pagamento_final, Money::from_reais(1000));
+        assert!(resultado.proventos.is_empty());
+    }
+
+    #[test]
+    fn test_arredondar_para_cima_negativo() {
+        let mut resultado = calcular_valores(
+            Money::ZERO,
+            Money::ZERO,
+            0,
+            vec![Deducao {
+                valor: Money::parse("20.50").unwrap(),
+                descricao: String::new(),
+            }],
+        );
+
+        // Para cima é em direção ao zero quando o líquido é negativo
+        assert_eq!(
+            resultado.arredondar_para_cima(),
+            Money::parse("0.50").unwrap()
+        );
+        assert_eq!(resultado.pagamento_final, Money::from_reais(-20));
+    }
+
+    #[test]
+    fn test_componentes_percentuais() {
+        let mut resultado = calcular_valores(
+            Money::from_reais(1000),
+            Money::parse("7.50").unwrap(),
+            20,
+            vec![Deducao {
+                valor: Money::from_reais(130),
+                descricao: "Adiantamento".to_string(),
+            }],
+        );
+        resultado.aplicar_bonus_assiduidade(Money::from_reais(50), 0);
+
+        let componentes = resultado.componentes_percentuais();
+
+        // Bruto: 1000 + 300 + 50 = 1350
+        let rotulos: Vec<&str> = componentes.iter().map(|c| c.0.as_str()).collect();
+        assert_eq!(
+            rotulos,
+            [
+                "Taxa fixa",
+                "Transporte",
+                "Bônus de assiduidade",
+                "Adiantamento"
+            ]
+        );
+        assert_eq!(componentes[1].1, Money::from_reais(300));
+        assert!((componentes[3].2 - 130.0 * 100.0 / 1350.0).abs() < 1e-9);
+
+        let soma_proventos: f64 = componentes[..3].iter().map(|c| c.2).sum();
+        assert!((soma_proventos - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_componentes_percentuais_sem_bruto() {
+        let resultado = calcular_valores(Money::ZERO, Money::ZERO, 0, vec![]);
+
+        assert!(resultado
+            .componentes_percentuais()
+            .iter()
+            .all(|c| c.2 == 0.0));
+    }
+
+    #[test]
+    fn test_dias_fracionarios() {
+        let resultado = calcular_valores_fracionarios(
+            Money::from_reais(100),
+            Money::from_reais(5),
+            18.5,
+            vec![],
+        );
+
+        // 18.5 × 2 × 5.00
+        assert_eq!(resultado.custo_transporte, Money::from_reais(185));
+        assert_eq!(resultado.dias_trabalhados, 18);
+        assert_eq!(resultado.dias_trabalhados_fracionarios, 18.5);
+        assert_eq!(resultado.pagamento_final, Money::from_reais(285));
+    }
+
+    #[test]
+    fn test_dias_fracionarios_inteiros_batem_com_calculo_padrao() {
+        let fracionario = calcular_valores_fracionarios(
+            Money::from_reais(100),
+            Money::parse("7.35").unwrap(),
+            20.0,
+            vec![],
+        );
+        let padrao = calcular_valores(
+            Money::from_reais(100),
+            Money::parse("7.35").unwrap(),
+            20,
+            vec![],
+        );
+
+        assert_eq!(fracionario.custo_transporte, padrao.custo_transporte);
+        assert_eq!(fracionario.pagamento_final, padrao.pagamento_final);
+        assert_eq!(padrao.dias_trabalhados_fracionarios, 20.0);
+    }
+
+    #[test]
+    fn test_piso_abaixo_complementa() {
+        let resultado = calcular_valores_com_piso(
+            Money::from_reais(1000),
+            Money::from_reais(5),
+            10,
+            vec![Deducao {
+                valor: Money::from_reais(200),
+                descricao: "Adiantamento".to_string(),
+            }],
+            Some(Money::from_reais(1412)),
+        );
+
+        // 1000 + 100 - 200 = 900, abaixo do piso
+        assert_eq!(resultado.complemento_piso, Money::from_reais(512));
+        assert_eq!(resultado.pagamento_final, Money::from_reais(1412));
+        assert_eq!(resultado.proventos_total, Money::from_reais(512));
+        assert_eq!(resultado.proventos[0].descricao, "Complemento de piso");
+    }
+
+    #[test]
+    fn test_piso_acima_nao_altera() {
+        let padrao = calcular_valores(Money::from_reais(2000), Money::from_reais(5), 20, vec![]);
+
+        for piso in [
+            Some(Money::from_reais(1412)),
+            Some(Money::from_reais(2200)),
+            None,
+        ] {
+            let resultado = calcular_valores_com_piso(
+                Money::from_reais(2000),
+                Money::from_reais(5),
+                20,
+                vec![],
+                piso,
+            );
+
+            assert_eq!(resultado.complemento_piso, Money::ZERO);
+            assert_eq!(resultado.pagamento_final, padrao.pagamento_final);
+            assert!(resultado.proventos.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_meios_dias_contam_uma_viagem() {
+        let resultado = calcular_valores_com_meios_dias(
+            Money::from_reais(1000),
+            Money::from_reais(5),
+            18,
+            3,
+            vec![Deducao {
+                valor: Money::from_reais(50),
+                descricao: String::new(),
+            }],
+        );
+
+        // 5 × (18 × 2 + 3)
+        assert_eq!(resultado.custo_transporte, Money::from_reais(195));
+        assert_eq!(resultado.pagamento_final, Money::from_reais(1145));
+        assert_eq!(resultado.dias_trabalhados, 18);
+        assert_eq!(resultado.meios_dias, 3);
+        assert_eq!(resultado.dias_trabalhados_fracionarios, 19.5);
+    }
+
+    #[test]
+    fn test_sem_meios_dias_igual_ao_calculo_padrao() {
+        let com_meios = calcular_valores_com_meios_dias(
+            Money::from_reais(1000),
+            Money::from_reais(5),
+            20,
+            0,
+            vec![],
+        );
+        let padrao = calcular_valores(Money::from_reais(1000), Money::from_reais(5), 20, vec![]);
+
+        assert_eq!(com_meios.custo_transporte, padrao.custo_transporte);
+        assert_eq!(com_meios.pagamento_final, padrao.pagamento_final);
+        assert_eq!(padrao.meios_dias, 0);
+    }
+
+    #[test]
+    fn test_dias_fracionarios_arredondam_transporte() {
+        // 0.25 × 2 × 0.05 = 0.025 → 0.03
+        let resultado =
+            calcular_valores_fracionarios(Money::ZERO, Money::from_centavos(5), 0.25, vec![]);
+
+        assert_eq!(resultado.custo_transporte, Money::from_centavos(3));
+    }
+
+    #[test]
+    fn test_dias_fracionarios_com_deducoes() {
+        // 18.25 × 2 × 7.35 = 268.275 → 268.28
+        let resultado = calcular_valores_fracionarios(
+            Money::from_reais(100),
+            Money::parse("7.35").unwrap(),
+            18.25,
+            vec![Deducao {
+                valor: Money::from_reais(20),
+                descricao: "Adiantamento".to_string(),
+            }],
+        );
+
+        assert_eq!(resultado.custo_transporte, Money::parse("268.28").unwrap());
+        assert_eq!(resultado.pagamento_final, Money::parse("348.28").unwrap());
+    }
+
+    #[test]
+    fn test_diaria_por_dias_corridos() {
+        let mut resultado =
+            calcular_valores(Money::from_reais(100), Money::from_reais(5), 10, vec![]);
+        let dias = contar_dias_corridos(data(2024, 11, 25), data(2024, 12, 6)).unwrap();
+
+        let diaria = resultado.aplicar_diaria(Money::from_reais(80), dias);
+
+        assert_eq!(diaria, Money::from_reais(960));
+        assert_eq!(resultado.proventos[0].descricao, "Diária");
+        // 100 + 10 × 2 × 5 + 960
+        assert_eq!(resultado.pagamento_final, Money::from_reais(1160));
+    }
+
+    #[test]
+    fn test_dias_uteis_decorridos_ano_invalido() {
+        assert_eq!(
+            dias_uteis_decorridos(data(1850, 1, 10), &[]),
+            Err(ErroCalculo::AnoInvalido(1850))
+        );
+    }
+
+    #[test]
+    fn test_money_format_brl() {
+        assert_eq!(Money::ZERO.format_brl(), "0,00");
+        assert_eq!(Money::from_centavos(5).format_brl(), "0,05");
+        assert_eq!(Money::parse("7.50").unwrap().format_brl(), "7,50");
+        assert_eq!(Money::parse("999.99").unwrap().format_brl(), "999,99");
+        assert_eq!(Money::parse("1234.56").unwrap().format_brl(), "1.234,56");
+        assert_eq!(
+            Money::parse("1234567.89").unwrap().format_brl(),
+            "1.234.567,89"
+        );
+        assert_eq!(Money::parse("-20.50").unwrap().format_brl(), "-20,50");
+        assert_eq!(Money::parse("-0.50").unwrap().format_brl(), "-0,50");
+    }
+
+    #[test]
+    fn test_money_format_brl_com_simbolo() {
+        assert_eq!(Money::ZERO.format_brl_com_simbolo(), "R$ 0,00");
+        assert_eq!(
+            Money::parse("1234.56").unwrap().format_brl_com_simbolo(),
+            "R$ 1.234,56"
+        );
+        assert_eq!(
+            Money::parse("-20.50").unwrap().format_brl_com_simbolo(),
+            "-R$ 20,50"
+        );
+        assert_eq!(
+            Money::parse("-1000000").unwrap().format_brl_com_simbolo(),
+            "-R$ 1.000.000,00"
+        );
+    }
+
+    #[test]
+    fn test_money_percent_of() {
+        let total = Money::from_reais(200);
+
+        assert_eq!(Money::from_reais(50).percent_of(total), 25.0);
+        assert_eq!(total.percent_of(total), 100.0);
+        assert_eq!(Money::from_reais(50).percent_of(Money::ZERO), 0.0);
+        assert_eq!(Money::from_reais(-50).percent_of(total), -25.0);
+    }
+
+    #[test]
+    fn test_dias_uteis_com_feriados_nacionais() {
+        // Novembro de 2024: 21 dias úteis, Finados cai num sábado,
+        // Proclamação numa sexta e Consciência Negra numa quarta
+        assert_eq!(
+            feriados_nacionais_em_dias_uteis(11, 2024),
+            Ok(vec![data(2024, 11, 15), data(2024, 11, 20)])
+        );
+        assert_eq!(contar_dias_uteis_com_feriados(11, 2024), Ok(19));
+
+        // Fevereiro de 2024 (bissexto): 21 dias úteis e Carnaval em 12 e 13
+        assert_eq!(contar_dias_uteis_com_feriados(2, 2024), Ok(19));
+        // Março de 2024: Sexta-feira Santa em 29
+        assert_eq!(contar_dias_uteis_com_feriados(3, 2024), Ok(20));
+        // Junho de 2024 não tem feriado nacional
+        assert_eq!(
+            contar_dias_uteis_com_feriados(6, 2024),
+            contar_dias_uteis(6, 2024)
+        );
+    }
+
+    #[test]
+    fn test_dias_uteis_com_feriados_entrada_invalida() {
+        assert_eq!(
+            contar_dias_uteis_com_feriados(13, 2024),
+            Err(ErroCalculo::MesInvalido(13))
+        );
+        assert_eq!(
+            feriados_nacionais_em_dias_uteis(1, 2101),
+            Err(ErroCalculo::AnoInvalido(2101))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_money_em_centavos() {
+        let valor = Money::parse("-1234.56").unwrap();
+
+        let json = serde_json::to_string(&valor).unwrap();
+
+        assert_eq!(json, "-123456");
+        assert_eq!(serde_json::from_str::<Money>(&json).unwrap(), valor);
+        assert_eq!(
+            serde_json::from_str::<Money>("9007199254740993").unwrap(),
+            Money::from_centavos(9007199254740993)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_resultado_round_trip() {
+        let mut resultado = calcular_valores_com_calendario(
+            Money::parse("150.10").unwrap(),
+            Money::parse("7.35").unwrap(),
+            3,
+            2024,
+            1,
+            vec![Deducao {
+                valor: Money::parse("0.01").unwrap(),
+                descricao: "ajuste".to_string(),
+            }],
+        )
+        .unwrap();
+        resultado.aplicar_bonus_assiduidade(Money::parse("33.33").unwrap(), 0);
+
+        let json = serde_json::to_string(&resultado).unwrap();
+        let lido: ResultadoCalculo = serde_json::from_str(&json).unwrap();
+
+        assert!(json.contains("\"pagamento_final\":47742"));
+        assert!(json.contains("\"nome_mes\":\"Março\""));
+        assert_eq!(lido.pagamento_final, resultado.pagamento_final);
+        assert_eq!(lido.deducoes[0].valor, Money::from_centavos(1));
+        assert_eq!(lido.proventos_total, Money::parse("33.33").unwrap());
+        let calendario = lido.calendario.unwrap();
+        assert_eq!(calendario.nome_mes, Some("Março"));
+        assert_eq!(calendario.dias_trabalhados, 20);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_nome_mes_desconhecido() {
+        let json = r#"{"mes":3,"ano":2024,"nome_mes":"Marzo","dias_uteis_mes":21,
+            "feriados_deduzidos":0,"dias_trabalhados":21}"#;
+
+        assert!(serde_json::from_str::<InformacaoCalendario>(json).is_err());
+    }
+
+    #[test]
+    fn test_calculo_basico() {
+        let resultado = calcular_valores(Money::from_reais(100), Money::from_reais(5), 10, vec![]);
+
+        assert_eq!(resultado.custo_transporte, Money::from_reais(100)); // 5 * 10 * 2
+        assert_eq!(resultado.pagamento_final, Money::from_reais(200)); // 100 + 100 - 0
+    }
+
+    #[test]
+    fn test_calculo_com_componentes_fixos() {
+        let componentes = vec![
+            ("Salário base".to_string(), Money::parse("1412.00").unwrap()),
+            ("Gratificação".to_string(), Money::parse("250.50").unwrap()),
+        ];
+
+        let resultado = calcular_valores_com_componentes_fixos(
+            componentes.clone(),
+            Money::parse("7.50").unwrap(),
+            8,
+            vec![],
+        );
+        let unica = calcular_valores(
+            Money::parse("1662.50").unwrap(),
+            Money::parse("7.50").unwrap(),
+            8,
+            vec![],
+        );
+
+        assert_eq!(resultado.taxa_fixa, Money::parse("1662.50").unwrap());
+        assert_eq!(resultado.componentes_fixos, componentes);
+        assert_eq!(resultado.pagamento_final, unica.pagamento_final);
+        assert!(unica.componentes_fixos.is_empty());
+    }
+
+    #[test]
+    fn test_calculo_com_viagens_por_dia() {
+        let padrao = calcular_valores(Money::from_reais(100), Money::from_reais(5), 10, vec![]);
+        let um_trajeto = calcular_valores_com_viagens(
+            Money::from_reais(100),
+            Money::from_reais(5),
+            10,
+            1,
+            vec![],
+        );
+        let almoco_em_casa = calcular_valores_com_viagens(
+            Money::from_reais(100),
+            Money::from_reais(5),
+            10,
+            4,
+            vec![],
+        );
+
+        assert_eq!(padrao.viagens_por_dia, 2);
+        assert_eq!(um_trajeto.viagens_por_dia, 1);
+        assert_eq!(um_trajeto.custo_transporte, Money::from_reais(50)); // 5 * 10 * 1
+        assert_eq!(almoco_em_casa.custo_transporte, Money::from_reais(200)); // 5 * 10 * 4
+        assert_eq!(almoco_em_casa.pagamento_final, Money::from_reais(300));
+    }
+
+    #[test]
+    fn test_calculo_com_deducoes() {
+        let resultado = calcular_valores(
+            Money::from_reais(150),
             Money::parse("7.50").unwrap(),
             8,
             vec![Deducao {
@@ -498,123 +2891,712 @@ mod tests {
                 },
             ],
         );
-
-        assert_eq!(resultado.custo_transporte, Money::from_reais(120)); // 7.50 * 8 * 2
-        assert_eq!(resultado.deducoes_total, Money::from_reais(25)); // 15 + 10
-        assert_eq!(resultado.pagamento_final, Money::from_reais(245)); // 150 + 120 - 25
-        assert_eq!(resultado.deducoes.len(), 2);
+
+        assert_eq!(resultado.custo_transporte, Money::from_reais(120)); // 7.50 * 8 * 2
+        assert_eq!(resultado.deducoes_total, Money::from_reais(25)); // 15 + 10
+        assert_eq!(resultado.pagamento_final, Money::from_reais(245)); // 150 + 120 - 25
+        assert_eq!(resultado.deducoes.len(), 2);
+    }
+
+    #[test]
+    fn test_calculo_com_deducoes_e_estornos() {
+        let deducoes = vec![
+            Deducao {
+                valor: Money::parse("50.00").unwrap(),
+                descricao: "Vale refeição adiantado".to_string(),
+            },
+            Deducao {
+                valor: Money::parse("80.00").unwrap(),
+                descricao: "Falta não justificada".to_string(),
+            },
+            Deducao {
+                valor: Money::parse("-12.35").unwrap(),
+                descricao: "Estorno de desconto".to_string(),
+            },
+        ];
+        let soma: Money = deducoes.iter().map(|d| d.valor).sum();
+
+        let resultado = calcular_valores(Money::from_reais(500), Money::ZERO, 0, deducoes);
+
+        assert_eq!(resultado.deducoes_total, soma);
+        assert_eq!(resultado.deducoes_total, Money::parse("117.65").unwrap());
+        assert_eq!(resultado.pagamento_final, Money::parse("382.35").unwrap());
+        assert_eq!(resultado.deducoes[2].descricao, "Estorno de desconto");
+    }
+
+    #[test]
+    fn test_calculo_com_deducao_total() {
+        let total = calcular_valores_com_deducao_total(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            8,
+            Money::from_reais(25),
+        );
+        let sem_deducao =
+            calcular_valores_com_deducao_total(Money::from_reais(150), Money::ZERO, 0, Money::ZERO);
+
+        assert_eq!(total.pagamento_final, Money::from_reais(245));
+        assert_eq!(total.deducoes.len(), 1);
+        assert_eq!(total.deducoes[0].descricao, "");
+        assert!(sem_deducao.deducoes.is_empty());
+        assert_eq!(sem_deducao.pagamento_final, Money::from_reais(150));
+    }
+
+    #[test]
+    fn test_valores_zero() {
+        let resultado = calcular_valores(Money::ZERO, Money::ZERO, 0, vec![]);
+
+        assert_eq!(resultado.custo_transporte, Money::ZERO);
+        assert_eq!(resultado.pagamento_final, Money::ZERO);
+    }
+
+    #[test]
+    fn test_apenas_taxa_fixa() {
+        let resultado = calcular_valores(Money::from_reais(200), Money::ZERO, 5, vec![]);
+
+        assert_eq!(resultado.custo_transporte, Money::ZERO);
+        assert_eq!(resultado.pagamento_final, Money::from_reais(200));
+    }
+
+    #[test]
+    fn test_apenas_transporte() {
+        let resultado = calcular_valores(Money::ZERO, Money::from_reais(10), 6, vec![]);
+
+        assert_eq!(resultado.custo_transporte, Money::from_reais(120)); // 10 * 6 * 2
+        assert_eq!(resultado.pagamento_final, Money::from_reais(120));
+    }
+
+    #[test]
+    fn test_deducoes_maiores_que_pagamento() {
+        let resultado = calcular_valores(
+            Money::from_reais(50),
+            Money::from_reais(5),
+            3,
+            vec![Deducao {
+                valor: Money::from_reais(100),
+                descricao: "grande dedução".to_string(),
+            }],
+        );
+
+        assert_eq!(resultado.custo_transporte, Money::from_reais(30)); // 5 * 3 * 2
+        assert_eq!(resultado.pagamento_final, Money::from_centavos(-2000)); // 50 + 30 - 100 = -20
+    }
+
+    #[test]
+    fn test_valores_decimais() {
+        let resultado = calcular_valores(
+            Money::parse("123.45").unwrap(),
+            Money::parse("3.75").unwrap(),
+            4,
+            vec![Deducao {
+                valor: Money::parse("15.50").unwrap(),
+                descricao: "desconto".to_string(),
+            }],
+        );
+
+        assert_eq!(resultado.custo_transporte, Money::from_reais(30)); // 3.75 * 4 * 2
+        assert_eq!(resultado.pagamento_final, Money::parse("137.95").unwrap()); // 123.45 + 30 - 15.50
+    }
+
+    #[test]
+    fn test_money_display() {
+        assert_eq!(format!("{}", Money::from_centavos(12345)), "123.45");
+        assert_eq!(format!("{}", Money::from_centavos(750)), "7.50");
+        assert_eq!(format!("{}", Money::from_reais(100)), "100.00");
+        assert_eq!(format!("{}", Money::from_centavos(-2050)), "-20.50");
+        assert_eq!(format!("{}", Money::from_centavos(-750)), "-7.50");
+        assert_eq!(format!("{}", Money::from_centavos(-50)), "-0.50");
+    }
+
+    #[test]
+    fn test_money_display_precisao() {
+        let valor = Money::from_centavos(12345);
+
+        assert_eq!(format!("{:.0}", valor), "123");
+        assert_eq!(format!("{:.1}", valor), "123.5");
+        assert_eq!(format!("{:.2}", valor), "123.45");
+        assert_eq!(format!("{:.4}", valor), "123.4500");
+
+        assert_eq!(format!("{:.0}", Money::from_centavos(12350)), "124");
+        assert_eq!(format!("{:.0}", Money::from_centavos(-12350)), "-124");
+        assert_eq!(format!("{:.1}", Money::from_centavos(-2045)), "-20.5");
+        assert_eq!(format!("{:.1}", Money::from_centavos(999)), "10.0");
+        assert_eq!(format!("{:.0}", Money::from_centavos(-40)), "0");
+    }
+
+    #[test]
+    fn test_deducoes_percentuais_por_base() {
+        let calcular = |base| {
+            calcular_valores_com_deducoes_percentuais(
+                Money::from_reais(1500),
+                Money::parse("7.50").unwrap(),
+                20, // transporte de 300.00
+                vec![Deducao {
+                    valor: Money::from_reais(50),
+                    descricao: "Adiantamento".to_string(),
+                }],
+                &[("Plano de saúde".to_string(), 8.0)],
+                base,
+            )
+        };
+
+        let total = calcular(BaseDeducao::Total);
+        let taxa_fixa = calcular(BaseDeducao::ApenasTaxaFixa);
+
+        // 8% de 1800.00 e 8% de 1500.00
+        assert_eq!(total.deducoes[1].valor, Money::from_reais(144));
+        assert_eq!(taxa_fixa.deducoes[1].valor, Money::from_reais(120));
+        assert_eq!(total.deducoes[1].descricao, "Plano de saúde");
+        assert_eq!(total.deducoes_total, Money::from_reais(194));
+        assert_eq!(total.pagamento_final, Money::from_reais(1606));
+        assert_eq!(taxa_fixa.pagamento_final, Money::from_reais(1630));
+        assert_eq!(BaseDeducao::default(), BaseDeducao::Total);
+    }
+
+    #[test]
+    fn test_deducoes_percentuais_arredondamento_por_item_e_na_soma() {
+        let percentuais = [
+            ("Previdência".to_string(), 7.5),
+            ("Sindicato".to_string(), 2.5),
+        ];
+        let calcular = |arredondamento| {
+            calcular_valores_com_deducoes_percentuais_e_arredondamento(
+                Money::parse("1234.55").unwrap(),
+                Money::ZERO,
+                0,
+                vec![],
+                &percentuais,
+                BaseDeducao::ApenasTaxaFixa,
+                arredondamento,
+            )
+        };
+
+        // 7.5% = 92.59125 e 2.5% = 30.86375; juntos, 10% = 123.455
+        let por_item = calcular(ArredondamentoDeducoes::PorItem);
+        assert_eq!(por_item.deducoes[0].valor, Money::parse("92.59").unwrap());
+        assert_eq!(por_item.deducoes[1].valor, Money::parse("30.86").unwrap());
+        assert_eq!(por_item.deducoes_total, Money::parse("123.45").unwrap());
+
+        let na_soma = calcular(ArredondamentoDeducoes::NaSoma);
+        assert_eq!(na_soma.deducoes[0].valor, Money::parse("92.59").unwrap());
+        assert_eq!(na_soma.deducoes[1].valor, Money::parse("30.87").unwrap());
+        assert_eq!(na_soma.deducoes_total, Money::parse("123.46").unwrap());
+        assert_eq!(
+            na_soma.pagamento_final,
+            por_item.pagamento_final - Money::from_centavos(1)
+        );
+
+        assert_eq!(
+            ArredondamentoDeducoes::default(),
+            ArredondamentoDeducoes::NaSoma
+        );
+        assert_eq!(
+            calcular_valores_com_deducoes_percentuais(
+                Money::parse("1234.55").unwrap(),
+                Money::ZERO,
+                0,
+                vec![],
+                &percentuais,
+                BaseDeducao::ApenasTaxaFixa,
+            )
+            .deducoes_total,
+            na_soma.deducoes_total
+        );
+    }
+
+    #[test]
+    fn test_deducoes_percentuais_sem_percentuais_igual_ao_calculo_padrao() {
+        let resultado = calcular_valores_com_deducoes_percentuais(
+            Money::from_reais(1500),
+            Money::parse("7.50").unwrap(),
+            20,
+            vec![],
+            &[],
+            BaseDeducao::ApenasTaxaFixa,
+        );
+        let padrao = calcular_valores(
+            Money::from_reais(1500),
+            Money::parse("7.50").unwrap(),
+            20,
+            vec![],
+        );
+
+        assert_eq!(resultado.pagamento_final, padrao.pagamento_final);
+        assert!(resultado.deducoes.is_empty());
+    }
+
+    #[test]
+    fn test_estrutura_retorno() {
+        let resultado = calcular_valores(
+            Money::from_reais(100),
+            Money::from_reais(5),
+            2,
+            vec![Deducao {
+                valor: Money::from_reais(10),
+                descricao: "teste".to_string(),
+            }],
+        );
+
+        // Verifica se os valores de entrada são preservados
+        assert_eq!(resultado.taxa_fixa, Money::from_reais(100));
+        assert_eq!(resultado.taxa_transporte, Money::from_reais(5));
+        assert_eq!(resultado.dias_trabalhados, 2);
+        assert_eq!(resultado.deducoes_total, Money::from_reais(10));
+    }
+
+    #[test]
+    fn test_calcular_transporte_simples() {
+        let taxa = Money::parse("7.50").unwrap();
+
+        assert_eq!(
+            calcular_transporte_simples(taxa, 8, 2),
+            calcular_valores(Money::from_reais(150), taxa, 8, vec![]).custo_transporte
+        );
+        assert_eq!(
+            calcular_transporte_simples(taxa, 8, 1),
+            Money::from_reais(60)
+        );
+        assert_eq!(
+            calcular_transporte_simples(taxa, 8, 4),
+            Money::from_reais(240)
+        );
+        assert_eq!(calcular_transporte_simples(taxa, 0, 2), Money::ZERO);
+    }
+
+    #[test]
+    fn test_bonus_assiduidade_sem_faltas() {
+        let mut resultado =
+            calcular_valores(Money::from_reais(100), Money::from_reais(5), 10, vec![]);
+
+        let concedido = resultado.aplicar_bonus_assiduidade(Money::from_reais(50), 0);
+
+        assert!(concedido);
+        assert_eq!(resultado.proventos.len(), 1);
+        assert_eq!(resultado.proventos_total, Money::from_reais(50));
+        assert_eq!(resultado.pagamento_final, Money::from_reais(250)); // 100 + 100 + 50
+    }
+
+    #[test]
+    fn test_bonus_assiduidade_com_faltas() {
+        let mut resultado =
+            calcular_valores(Money::from_reais(100), Money::from_reais(5), 9, vec![]);
+
+        let concedido = resultado.aplicar_bonus_assiduidade(Money::from_reais(50), 1);
+
+        assert!(!concedido);
+        assert!(resultado.proventos.is_empty());
+        assert_eq!(resultado.proventos_total, Money::ZERO);
+        assert_eq!(resultado.pagamento_final, Money::from_reais(190)); // 100 + 90
+    }
+
+    #[test]
+    fn test_reembolso_estacionamento_proporcional_aos_dias() {
+        let mut resultado = calcular_valores_com_calendario(
+            Money::from_reais(150),
+            Money::ZERO,
+            11,
+            2024,
+            2,
+            vec![],
+        )
+        .unwrap();
+
+        let reembolso = resultado.aplicar_estacionamento(Money::parse("12.50").unwrap());
+
+        assert_eq!(reembolso, Money::parse("237.50").unwrap()); // 12.50 × 19 dias
+        assert_eq!(resultado.proventos[0].descricao, "Estacionamento");
+        assert_eq!(resultado.proventos_total, reembolso);
+        assert_eq!(resultado.pagamento_final, Money::parse("387.50").unwrap()); // 150 + 237.50
+    }
+
+    #[test]
+    fn test_reembolso_estacionamento_sem_dias() {
+        let mut resultado = calcular_valores(Money::from_reais(100), Money::ZERO, 0, vec![]);
+
+        assert_eq!(
+            resultado.aplicar_estacionamento(Money::from_reais(20)),
+            Money::ZERO
+        );
+        assert_eq!(resultado.pagamento_final, Money::from_reais(100));
+    }
+
+    #[test]
+    fn test_soma_de_resultados() {
+        let novembro = calcular_valores_com_calendario(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            11,
+            2024,
+            2,
+            vec![Deducao {
+                valor: Money::from_reais(25),
+                descricao: "adiantamento".to_string(),
+            }],
+        )
+        .unwrap();
+        let mut dezembro = calcular_valores_com_calendario(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            12,
+            2024,
+            1,
+            vec![],
+        )
+        .unwrap();
+        dezembro.aplicar_bonus_assiduidade(Money::from_reais(40), 0);
+
+        let soma = novembro.clone() + dezembro.clone();
+
+        assert_eq!(soma.taxa_fixa, novembro.taxa_fixa + dezembro.taxa_fixa);
+        assert_eq!(soma.taxa_transporte, Money::parse("7.50").unwrap());
+        assert_eq!(
+            soma.dias_trabalhados,
+            novembro.dias_trabalhados + dezembro.dias_trabalhados
+        );
+        assert_eq!(
+            soma.custo_transporte,
+            novembro.custo_transporte + dezembro.custo_transporte
+        );
+        assert_eq!(soma.proventos_total, Money::from_reais(40));
+        assert_eq!(soma.proventos.len(), 1);
+        assert_eq!(soma.deducoes_total, Money::from_reais(25));
+        assert_eq!(soma.deducoes.len(), 1);
+        assert_eq!(
+            soma.pagamento_final,
+            novembro.pagamento_final + dezembro.pagamento_final
+        );
+        assert!(soma.calendario.is_none());
+    }
+
+    #[test]
+    fn test_soma_de_resultados_com_tarifas_diferentes() {
+        let a = calcular_valores(Money::from_reais(100), Money::from_reais(5), 10, vec![]);
+        let b = calcular_valores(Money::from_reais(100), Money::from_reais(6), 10, vec![]);
+
+        let soma = a.clone() + b;
+
+        assert_eq!(soma.taxa_transporte, Money::ZERO);
+        assert_eq!(soma.viagens_por_dia, 2);
+        let quatro_viagens =
+            calcular_valores_com_viagens(Money::ZERO, Money::from_reais(5), 10, 4, vec![]);
+        assert_eq!((a + quatro_viagens).viagens_por_dia, 0);
+        assert_eq!(soma.custo_transporte, Money::from_reais(220)); // 100 + 120
+        assert_eq!(soma.pagamento_final, Money::from_reais(420));
+    }
+
+    #[test]
+    fn test_soma_de_resultados_mescla_componentes_fixos() {
+        let componentes = vec![
+            ("Salário".to_string(), Money::from_reais(1000)),
+            ("Adicional".to_string(), Money::from_reais(200)),
+        ];
+        let a = calcular_valores_com_componentes_fixos(
+            componentes.clone(),
+            Money::from_reais(5),
+            10,
+            vec![],
+        );
+        let b = calcular_valores_com_componentes_fixos(
+            vec![("Adicional".to_string(), Money::from_reais(50))],
+            Money::from_reais(5),
+            10,
+            vec![],
+        );
+
+        let soma = a.clone() + b;
+
+        assert_eq!(
+            soma.componentes_fixos,
+            vec![
+                ("Salário".to_string(), Money::from_reais(1000)),
+                ("Adicional".to_string(), Money::from_reais(250)),
+            ]
+        );
+
+        let simples = calcular_valores(Money::from_reais(300), Money::from_reais(5), 10, vec![]);
+        let soma = simples + a;
+        assert_eq!(soma.taxa_fixa, Money::from_reais(1500));
+        assert_eq!(
+            soma.componentes_fixos,
+            vec![
+                ("Taxa fixa".to_string(), Money::from_reais(300)),
+                ("Salário".to_string(), Money::from_reais(1000)),
+                ("Adicional".to_string(), Money::from_reais(200)),
+            ]
+        );
+        let total: Money = soma.componentes_fixos.iter().map(|(_, valor)| *valor).sum();
+        assert_eq!(total, soma.taxa_fixa);
+    }
+
+    // Testes para funcionalidade de calendário
+
+    #[test]
+    fn test_contar_dias_uteis_novembro_2024() {
+        // Novembro 2024: tem 21 dias úteis (1-30, excluindo sábados e domingos)
+        let dias_uteis = contar_dias_uteis(11, 2024).unwrap();
+        assert_eq!(dias_uteis, 21);
+    }
+
+    #[test]
+    fn test_contar_dias_uteis_fevereiro_2024() {
+        // Fevereiro 2024 (ano bissexto): tem 21 dias úteis
+        let dias_uteis = contar_dias_uteis(2, 2024).unwrap();
+        assert_eq!(dias_uteis, 21);
+    }
+
+    #[test]
+    fn test_contar_dias_uteis_mes_invalido() {
+        assert!(contar_dias_uteis(0, 2024).is_err());
+        assert!(contar_dias_uteis(13, 2024).is_err());
+    }
+
+    #[test]
+    fn test_contar_dias_uteis_ano_invalido() {
+        assert!(contar_dias_uteis(1, 1899).is_err());
+        assert!(contar_dias_uteis(1, 2101).is_err());
+    }
+
+    #[test]
+    fn test_feriados_uteis_no_ano() {
+        // 2023: só 01/01 cai no fim de semana e ainda não havia a
+        // Consciência Negra
+        assert_eq!(feriados_uteis_no_ano(2023), Ok(11));
+        // 2024: Tiradentes, Independência, Aparecida e Finados no fim de semana
+        assert_eq!(feriados_uteis_no_ano(2024), Ok(9));
+        // 2026: só a Proclamação da República (15/11) cai no domingo
+        assert_eq!(feriados_uteis_no_ano(2026), Ok(12));
+        // 2022: 01/01, 01/05 e 25/12 no fim de semana
+        assert_eq!(feriados_uteis_no_ano(2022), Ok(9));
+        assert!(feriados_uteis_no_ano(1899).is_err());
+    }
+
+    fn confirmar(mes: u32, ano: i32, digitado: &str) -> (Vec<NaiveDate>, String) {
+        let mut saida = Vec::new();
+        let datas =
+            confirmar_feriados_nacionais(mes, ano, &mut digitado.as_bytes(), &mut saida).unwrap();
+        (datas, String::from_utf8(saida).unwrap())
+    }
+
+    #[test]
+    fn test_confirmar_feriados_desmarcando_um() {
+        // Novembro de 2024: 15 (sexta) e 20 (quarta); Finados cai no sábado
+        let (datas, saida) = confirmar(11, 2024, "1\n\n");
+
+        assert_eq!(datas, vec![data(2024, 11, 20)]);
+        assert!(saida.contains("  [x] 1. 15/11 - Proclamação da República\n"));
+        assert!(saida.contains("  [ ] 1. 15/11 - Proclamação da República\n"));
+        assert!(saida.contains("  [x] 2. 20/11 - Consciência Negra\n"));
+        assert!(!saida.contains("02/11"));
+
+        let resultado = calcular_valores_com_datas_feriados_e_jornada(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            11,
+            2024,
+            &datas,
+            &JORNADA_PADRAO,
+            vec![],
+        )
+        .unwrap();
+        assert_eq!(resultado.dias_trabalhados, 20);
+    }
+
+    #[test]
+    fn test_confirmar_feriados_aceita_todos_e_remarca() {
+        assert_eq!(
+            confirmar(11, 2024, "\n").0,
+            vec![data(2024, 11, 15), data(2024, 11, 20)]
+        );
+        // Desmarcar e marcar de novo; o fim da entrada também confirma
+        assert_eq!(
+            confirmar(11, 2024, "2\n2\n").0,
+            vec![data(2024, 11, 15), data(2024, 11, 20)]
+        );
+        assert_eq!(confirmar(11, 2024, "1\n2\n").0, vec![]);
     }
 
     #[test]
-    fn test_valores_zero() {
-        let resultado = calcular_valores(Money::ZERO, Money::ZERO, 0, vec![]);
+    fn test_confirmar_feriados_entrada_invalida_e_mes_sem_feriados() {
+        let (datas, saida) = confirmar(11, 2024, "3\nabc\n\n");
+        assert_eq!(datas.len(), 2);
+        assert_eq!(
+            saida.matches("Erro: Digite um número entre 1 e 2.").count(),
+            2
+        );
 
-        assert_eq!(resultado.custo_transporte, Money::ZERO);
-        assert_eq!(resultado.pagamento_final, Money::ZERO);
+        let (datas, saida) = confirmar(8, 2024, "");
+        assert!(datas.is_empty());
+        assert_eq!(
+            saida,
+            "Nenhum feriado nacional em dia útil em Agosto de 2024.\n"
+        );
+
+        let mut saida = Vec::new();
+        assert!(confirmar_feriados_nacionais(13, 2024, &mut "".as_bytes(), &mut saida).is_err());
     }
 
-    #[test]
-    fn test_apenas_taxa_fixa() {
-        let resultado = calcular_valores(Money::from_reais(200), Money::ZERO, 5, vec![]);
+    // Escrita que sempre falha, como um stdout fechado
+    struct SaidaFechada;
 
-        assert_eq!(resultado.custo_transporte, Money::ZERO);
-        assert_eq!(resultado.pagamento_final, Money::from_reais(200));
+    impl std::io::Write for SaidaFechada {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::from(std::io::ErrorKind::BrokenPipe))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
     }
 
     #[test]
-    fn test_apenas_transporte() {
-        let resultado = calcular_valores(Money::ZERO, Money::from_reais(10), 6, vec![]);
-
-        assert_eq!(resultado.custo_transporte, Money::from_reais(120)); // 10 * 6 * 2
-        assert_eq!(resultado.pagamento_final, Money::from_reais(120));
+    fn test_confirmar_feriados_falha_de_io_vira_erro() {
+        let erro = confirmar_feriados_nacionais(11, 2024, &mut "\n".as_bytes(), &mut SaidaFechada)
+            .unwrap_err();
+        assert!(matches!(erro, ErroCalculo::EntradaSaida(_)));
+        assert!(erro.to_string().starts_with("Falha de entrada/saída: "));
+
+        let mut saida = Vec::new();
+        let mut entrada = std::io::BufReader::new(&[0xff, 0xfe, b'\n'][..]);
+        assert!(confirmar_feriados_nacionais(11, 2024, &mut entrada, &mut saida).is_err());
     }
 
     #[test]
-    fn test_deducoes_maiores_que_pagamento() {
-        let resultado = calcular_valores(
-            Money::from_reais(50),
-            Money::from_reais(5),
-            3,
-            vec![Deducao {
-                valor: Money::from_reais(100),
-                descricao: "grande dedução".to_string(),
-            }],
+    fn test_contar_dias_uteis_erros_tipados() {
+        assert_eq!(
+            contar_dias_uteis(13, 2024),
+            Err(ErroCalculo::MesInvalido(13))
+        );
+        assert_eq!(
+            contar_dias_uteis(1, 1899),
+            Err(ErroCalculo::AnoInvalido(1899))
+        );
+        assert_eq!(
+            ErroCalculo::MesInvalido(13).to_string(),
+            "Mês deve estar entre 1 e 12"
         );
+    }
 
-        assert_eq!(resultado.custo_transporte, Money::from_reais(30)); // 5 * 3 * 2
-        assert_eq!(resultado.pagamento_final, Money::from_centavos(-2000)); // 50 + 30 - 100 = -20
+    #[test]
+    fn test_diferenca_dias_uteis() {
+        // Novembro 2024 (começa na sexta) tem 21; novembro 2023 (quarta) tem 22
+        assert_eq!(diferenca_dias_uteis(11, 2024, 2023).unwrap(), -1);
+        assert_eq!(diferenca_dias_uteis(11, 2023, 2024).unwrap(), 1);
+        // Fevereiro 2024 (bissexto) tem 21; fevereiro 2023 tem 20
+        assert_eq!(diferenca_dias_uteis(2, 2024, 2023).unwrap(), 1);
+        assert_eq!(diferenca_dias_uteis(5, 2024, 2024).unwrap(), 0);
     }
 
     #[test]
-    fn test_valores_decimais() {
-        let resultado = calcular_valores(
-            Money::parse("123.45").unwrap(),
-            Money::parse("3.75").unwrap(),
-            4,
-            vec![Deducao {
-                valor: Money::parse("15.50").unwrap(),
-                descricao: "desconto".to_string(),
-            }],
+    fn test_diferenca_dias_uteis_invalida() {
+        assert_eq!(
+            diferenca_dias_uteis(13, 2024, 2023),
+            Err(ErroCalculo::MesInvalido(13))
+        );
+        assert_eq!(
+            diferenca_dias_uteis(11, 2024, 2101),
+            Err(ErroCalculo::AnoInvalido(2101))
         );
-
-        assert_eq!(resultado.custo_transporte, Money::from_reais(30)); // 3.75 * 4 * 2
-        assert_eq!(resultado.pagamento_final, Money::parse("137.95").unwrap()); // 123.45 + 30 - 15.50
     }
 
     #[test]
-    fn test_money_display() {
-        assert_eq!(format!("{}", Money::from_centavos(12345)), "123.45");
-        assert_eq!(format!("{}", Money::from_centavos(750)), "7.50");
-        assert_eq!(format!("{}", Money::from_reais(100)), "100.00");
-        assert_eq!(format!("{}", Money::from_centavos(-2050)), "-20.50");
-        assert_eq!(format!("{}", Money::from_centavos(-750)), "-7.50");
+    fn test_contar_dia_semana() {
+        // Fevereiro 2024 (29 dias): só a quinta-feira aparece 5 vezes
+        assert_eq!(contar_dia_semana(2, 2024, Weekday::Thu).unwrap(), 5);
+        assert_eq!(contar_dia_semana(2, 2024, Weekday::Fri).unwrap(), 4);
+        // Fevereiro 2023 (28 dias): todos aparecem 4 vezes
+        assert_eq!(contar_dia_semana(2, 2023, Weekday::Wed).unwrap(), 4);
+        assert!(contar_dia_semana(13, 2024, Weekday::Mon).is_err());
     }
 
     #[test]
-    fn test_estrutura_retorno() {
-        let resultado = calcular_valores(
-            Money::from_reais(100),
-            Money::from_reais(5),
-            2,
-            vec![Deducao {
-                valor: Money::from_reais(10),
-                descricao: "teste".to_string(),
-            }],
-        );
+    fn test_semanas_de_pagamento() {
+        // Novembro 2024: sextas em 1, 8, 15, 22, 29
+        assert_eq!(semanas_de_pagamento(11, 2024, Weekday::Fri).unwrap(), 5);
+        // Novembro 2024: segundas em 4, 11, 18, 25
+        assert_eq!(semanas_de_pagamento(11, 2024, Weekday::Mon).unwrap(), 4);
+        // Dezembro 2024: segundas em 2, 9, 16, 23, 30
+        assert_eq!(semanas_de_pagamento(12, 2024, Weekday::Mon).unwrap(), 5);
+        assert!(semanas_de_pagamento(0, 2024, Weekday::Fri).is_err());
+    }
 
-        // Verifica se os valores de entrada são preservados
-        assert_eq!(resultado.taxa_fixa, Money::from_reais(100));
-        assert_eq!(resultado.taxa_transporte, Money::from_reais(5));
-        assert_eq!(resultado.dias_trabalhados, 2);
-        assert_eq!(resultado.deducoes_total, Money::from_reais(10));
+    #[test]
+    fn test_eh_bissexto() {
+        assert!(eh_bissexto(2000)); // século divisível por 400
+        assert!(!eh_bissexto(1900)); // século não divisível por 400
+        assert!(eh_bissexto(2024));
+        assert!(!eh_bissexto(2023));
     }
 
-    // Testes para funcionalidade de calendário
+    #[test]
+    fn test_validar_competencia_dentro_do_horizonte() {
+        let hoje = data(2024, 11, 18);
+
+        assert_eq!(validar_competencia_razoavel(11, 2024, hoje, 0), Ok(()));
+        assert_eq!(validar_competencia_razoavel(2, 2025, hoje, 3), Ok(()));
+        // Passado não tem limite
+        assert_eq!(validar_competencia_razoavel(1, 1990, hoje, 3), Ok(()));
+    }
 
     #[test]
-    fn test_contar_dias_uteis_novembro_2024() {
-        // Novembro 2024: tem 21 dias úteis (1-30, excluindo sábados e domingos)
-        let dias_uteis = contar_dias_uteis(11, 2024).unwrap();
-        assert_eq!(dias_uteis, 21);
+    fn test_validar_competencia_alem_do_horizonte() {
+        let hoje = data(2024, 11, 18);
+
+        assert_eq!(
+            validar_competencia_razoavel(3, 2025, hoje, 3),
+            Err(ErroCalculo::CompetenciaDistante {
+                meses_a_frente: 4,
+                maximo: 3
+            })
+        );
+        // 2099 digitado no lugar de 2024
+        assert_eq!(
+            validar_competencia_razoavel(11, 2099, hoje, 12)
+                .unwrap_err()
+                .to_string(),
+            "Competência 900 meses à frente excede o limite de 12"
+        );
+        assert_eq!(
+            validar_competencia_razoavel(13, 2024, hoje, 12),
+            Err(ErroCalculo::MesInvalido(13))
+        );
     }
 
     #[test]
-    fn test_contar_dias_uteis_fevereiro_2024() {
-        // Fevereiro 2024 (ano bissexto): tem 21 dias úteis
-        let dias_uteis = contar_dias_uteis(2, 2024).unwrap();
-        assert_eq!(dias_uteis, 21);
+    fn test_dias_no_mes() {
+        assert_eq!(dias_no_mes(2, 2024), Ok(29));
+        assert_eq!(dias_no_mes(2, 2023), Ok(28));
+        assert_eq!(dias_no_mes(2, 1900), Ok(28)); // secular não bissexto
+        assert_eq!(dias_no_mes(2, 2000), Ok(29));
+        assert_eq!(dias_no_mes(4, 2024), Ok(30));
+        assert_eq!(dias_no_mes(11, 2024), Ok(30));
+        assert_eq!(dias_no_mes(1, 2024), Ok(31));
+        assert_eq!(dias_no_mes(12, 2100), Ok(31));
     }
 
     #[test]
-    fn test_contar_dias_uteis_mes_invalido() {
-        assert!(contar_dias_uteis(0, 2024).is_err());
-        assert!(contar_dias_uteis(13, 2024).is_err());
+    fn test_dias_no_mes_invalido() {
+        assert_eq!(dias_no_mes(0, 2024), Err(ErroCalculo::MesInvalido(0)));
+        assert_eq!(dias_no_mes(13, 2024), Err(ErroCalculo::MesInvalido(13)));
+        assert_eq!(dias_no_mes(2, 1899), Err(ErroCalculo::AnoInvalido(1899)));
     }
 
     #[test]
-    fn test_contar_dias_uteis_ano_invalido() {
-        assert!(contar_dias_uteis(1, 1899).is_err());
-        assert!(contar_dias_uteis(1, 2101).is_err());
+    fn test_trimestre_do_mes() {
+        for (mes, trimestre) in [
+            (1, 1),
+            (3, 1),
+            (4, 2),
+            (6, 2),
+            (7, 3),
+            (9, 3),
+            (10, 4),
+            (12, 4),
+        ] {
+            assert_eq!(trimestre_do_mes(mes).unwrap(), trimestre);
+        }
+
+        assert_eq!(trimestre_do_mes(0), Err(ErroCalculo::MesInvalido(0)));
+        assert_eq!(trimestre_do_mes(13), Err(ErroCalculo::MesInvalido(13)));
     }
 
     #[test]
@@ -631,6 +3613,151 @@ mod tests {
         obter_nome_mes(13);
     }
 
+    #[test]
+    fn test_datas_feriados_com_folga_na_segunda() {
+        use Weekday::*;
+
+        // 01/01/2024 é segunda: com folga às segundas o feriado já não é
+        // dia útil e não desconta de novo
+        let feriados = [data(2024, 1, 1)];
+        let calcular = |dias_trabalho: &[Weekday]| {
+            calcular_valores_com_datas_feriados_e_jornada(
+                Money::ZERO,
+                Money::from_reais(5),
+                1,
+                2024,
+                &feriados,
+                dias_trabalho,
+                vec![],
+            )
+            .unwrap()
+        };
+
+        let folga_segunda = calcular(&[Tue, Wed, Thu, Fri]);
+        let padrao = calcular(&[Mon, Tue, Wed, Thu, Fri]);
+
+        let calendario = folga_segunda.calendario.unwrap();
+        assert_eq!(calendario.dias_uteis_mes, 18);
+        assert_eq!(calendario.feriados_deduzidos, 0);
+        assert_eq!(calendario.nome_mes, Some("Janeiro"));
+        assert_eq!(folga_segunda.dias_trabalhados, 18);
+        assert_eq!(folga_segunda.custo_transporte, Money::from_reais(180));
+        assert_eq!(padrao.calendario.unwrap().feriados_deduzidos, 1);
+        assert_eq!(padrao.dias_trabalhados, 22);
+    }
+
+    #[test]
+    fn test_datas_feriados_com_sabado_trabalhado() {
+        use Weekday::*;
+
+        // 02/11/2024 (Finados) é sábado: deduzido só para quem trabalha
+        // aos sábados; 15/11 é sexta e a data repetida conta uma vez
+        let feriados = [data(2024, 11, 2), data(2024, 11, 15), data(2024, 11, 15)];
+        let calcular = |dias_trabalho: &[Weekday]| {
+            calcular_valores_com_datas_feriados_e_jornada(
+                Money::ZERO,
+                Money::ZERO,
+                11,
+                2024,
+                &feriados,
+                dias_trabalho,
+                vec![],
+            )
+            .unwrap()
+        };
+
+        let com_sabado = calcular(&[Mon, Tue, Wed, Thu, Fri, Sat]);
+        let padrao = calcular(&[Mon, Tue, Wed, Thu, Fri]);
+
+        assert_eq!(com_sabado.dias_trabalhados, 26 - 2);
+        assert_eq!(padrao.dias_trabalhados, 21 - 1);
+        assert_eq!(
+            padrao.calendario.as_ref().unwrap().feriados,
+            vec![
+                FeriadoCalendario {
+                    data: data(2024, 11, 2),
+                    nome: Some("Finados".to_string()),
+                    deduzido: false,
+                },
+                FeriadoCalendario {
+                    data: data(2024, 11, 15),
+                    nome: Some("Proclamação da República".to_string()),
+                    deduzido: true,
+                },
+            ]
+        );
+        assert_eq!(
+            padrao.dias_trabalhados,
+            calcular_valores_com_calendario(Money::ZERO, Money::ZERO, 11, 2024, 1, vec![])
+                .unwrap()
+                .dias_trabalhados
+        );
+    }
+
+    #[test]
+    fn test_datas_feriados_com_jornada_ignora_outro_mes() {
+        let resultado = calcular_valores_com_datas_feriados_e_jornada(
+            Money::ZERO,
+            Money::ZERO,
+            11,
+            2024,
+            &[data(2024, 12, 25)],
+            &[Weekday::Wed],
+            vec![],
+        )
+        .unwrap();
+
+        assert_eq!(resultado.calendario.unwrap().feriados_deduzidos, 0);
+        assert!(calcular_valores_com_datas_feriados_e_jornada(
+            Money::ZERO,
+            Money::ZERO,
+            13,
+            2024,
+            &[],
+            &[Weekday::Wed],
+            vec![],
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_obter_nome_mes_lang() {
+        assert_eq!(obter_nome_mes_lang(11, Idioma::Pt), "Novembro");
+        assert_eq!(obter_nome_mes_lang(11, Idioma::En), "November");
+        assert_eq!(obter_nome_mes_lang(3, Idioma::En), "March");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_obter_nome_mes_lang_invalido() {
+        obter_nome_mes_lang(0, Idioma::En);
+    }
+
+    #[test]
+    fn test_resumo_pagamento() {
+        let entrada = EntradaCalculo {
+            nome: String::new(),
+            taxa_fixa: Money::from_reais(150),
+            taxa_transporte: Money::parse("7.50").unwrap(),
+            mes: 11,
+            ano: 2024,
+            feriados_deduzidos: 2,
+            deducoes: vec![],
+        };
+        let brl = OpcoesCli {
+            brl: true,
+            ..OpcoesCli::default()
+        };
+
+        let resumo = resumo_pagamento(&entrada, brl).unwrap();
+
+        assert!(resumo.contains("PAGAMENTO FINAL: R$ 435,00\n"));
+        assert_eq!(
+            resumo_pagamento(&EntradaCalculo { mes: 13, ..entrada }, OpcoesCli::default()),
+            Err(ErroCalculo::MesInvalido(13))
+        );
+    }
+
     #[test]
     fn test_calcular_valores_com_calendario() {
         let resultado = calcular_valores_com_calendario(
@@ -657,14 +3784,279 @@ mod tests {
         assert_eq!(resultado.pagamento_final, Money::from_reais(410));
 
         let calendario = resultado.calendario.unwrap();
-        assert_eq!(calendario.mes, 11);
-        assert_eq!(calendario.ano, 2024);
-        assert_eq!(calendario.nome_mes, "Novembro");
+        assert_eq!(calendario.mes, Some(11));
+        assert_eq!(calendario.ano, Some(2024));
+        assert_eq!(calendario.nome_mes, Some("Novembro"));
+        assert_eq!(calendario.dias_uteis_mes, 21);
+        assert_eq!(calendario.feriados_deduzidos, 2);
+        assert_eq!(calendario.dias_trabalhados, 19);
+    }
+
+    #[test]
+    fn test_calcular_valores_com_periodo() {
+        let resultado = calcular_valores_com_periodo(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            data(2024, 11, 11),
+            data(2024, 11, 30),
+            1, // 20/11
+            vec![],
+        )
+        .unwrap();
+
+        assert_eq!(resultado.dias_trabalhados, 14);
+        // 7.50 * 14 * 2 = 210.00
+        assert_eq!(resultado.custo_transporte, Money::from_reais(210));
+        let calendario = resultado.calendario.unwrap();
+        assert_eq!(calendario.nome_mes, Some("Novembro"));
+        assert_eq!(calendario.dias_uteis_mes, 15);
+    }
+
+    #[test]
+    fn test_calcular_valores_com_periodo_entre_meses() {
+        let resultado = calcular_valores_com_periodo(
+            Money::ZERO,
+            Money::from_reais(5),
+            data(2024, 12, 30),
+            data(2025, 1, 3),
+            0,
+            vec![],
+        )
+        .unwrap();
+
+        assert_eq!(resultado.dias_trabalhados, 5);
+        let calendario = resultado.calendario.unwrap();
+        assert_eq!(calendario.mes, None);
+        assert_eq!(calendario.nome_mes, None);
+        assert!(calcular_valores_com_periodo(
+            Money::ZERO,
+            Money::ZERO,
+            data(2025, 1, 3),
+            data(2024, 12, 30),
+            0,
+            vec![]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_calcular_por_datas() {
+        let datas = [
+            data(2024, 3, 4),
+            data(2024, 3, 5),
+            data(2024, 3, 4), // repetida
+            data(2024, 3, 9), // sábado trabalhado
+        ];
+
+        let resultado = calcular_por_datas(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            &datas,
+            vec![],
+        );
+
+        assert_eq!(resultado.dias_trabalhados, 3);
+        assert_eq!(resultado.custo_transporte, Money::from_reais(45));
+        assert_eq!(resultado.pagamento_final, Money::from_reais(195));
+    }
+
+    #[test]
+    fn test_calcular_valores_com_datas_feriados() {
+        let feriados = [
+            data(2024, 11, 15),
+            data(2024, 11, 2),  // sábado
+            data(2024, 12, 25), // outro mês
+            data(2024, 11, 20),
+            data(2024, 11, 15), // repetido
+        ];
+
+        let resultado = calcular_valores_com_datas_feriados(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            11,
+            2024,
+            &feriados,
+            vec![],
+        )
+        .unwrap();
+
+        let calendario = resultado.calendario.unwrap();
+        assert_eq!(calendario.feriados_informados, 4);
+        assert_eq!(calendario.feriados_deduzidos, 2);
+        assert_eq!(calendario.feriados.len(), 3);
+        assert_eq!(resultado.dias_trabalhados, 19);
+        assert_eq!(resultado.pagamento_final, Money::from_reais(435));
+    }
+
+    #[test]
+    fn test_calcular_valores_com_datas_feriados_nome_quando_conhecido() {
+        // 12/11/2024 não é feriado nacional: deduzido, mas sem nome
+        let resultado = calcular_valores_com_datas_feriados(
+            Money::ZERO,
+            Money::ZERO,
+            11,
+            2024,
+            &[data(2024, 11, 12), data(2024, 11, 15)],
+            vec![],
+        )
+        .unwrap();
+
+        let feriados = resultado.calendario.unwrap().feriados;
+        assert_eq!(feriados[0].nome, None);
+        assert!(feriados[0].deduzido);
+        assert_eq!(
+            feriados[1].nome.as_deref(),
+            Some("Proclamação da República")
+        );
+        assert_eq!(
+            calcular_valores_com_datas_feriados(Money::ZERO, Money::ZERO, 11, 1899, &[], vec![])
+                .unwrap_err(),
+            ErroCalculo::AnoInvalido(1899)
+        );
+    }
+
+    #[test]
+    fn test_feriados_informados_igual_a_quantidade() {
+        let resultado =
+            calcular_valores_com_calendario(Money::ZERO, Money::ZERO, 11, 2024, 2, vec![]).unwrap();
+
+        let calendario = resultado.calendario.unwrap();
+        assert_eq!(calendario.feriados_informados, 2);
+        assert!(calendario.feriados.is_empty());
+    }
+
+    #[test]
+    fn test_calcular_valores_com_feriados_nacionais() {
+        let resultado = calcular_valores_com_feriados_nacionais(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            11, // Novembro
+            2024,
+            vec![],
+        )
+        .unwrap();
+
+        // Finados (02/11) cai num sábado; 15/11 e 20/11 são deduzidos
+        let calendario = resultado.calendario.unwrap();
+        let feriados: Vec<(NaiveDate, Option<&str>, bool)> = calendario
+            .feriados
+            .iter()
+            .map(|f| (f.data, f.nome.as_deref(), f.deduzido))
+            .collect();
+        assert_eq!(
+            feriados,
+            [
+                (data(2024, 11, 2), Some("Finados"), false),
+                (data(2024, 11, 15), Some("Proclamação da República"), true),
+                (data(2024, 11, 20), Some("Consciência Negra"), true),
+            ]
+        );
+        assert_eq!(calendario.feriados_deduzidos, 2);
+        assert_eq!(resultado.dias_trabalhados, 19);
+        assert_eq!(resultado.pagamento_final, Money::from_reais(435));
+    }
+
+    #[test]
+    fn test_calcular_valores_com_feriados_nacionais_mes_sem_feriados() {
+        let resultado =
+            calcular_valores_com_feriados_nacionais(Money::ZERO, Money::ZERO, 8, 2024, vec![])
+                .unwrap();
+
+        assert!(resultado.calendario.unwrap().feriados.is_empty());
+        assert_eq!(resultado.dias_trabalhados, 22);
+        assert_eq!(
+            calcular_valores_com_feriados_nacionais(Money::ZERO, Money::ZERO, 13, 2024, vec![])
+                .unwrap_err(),
+            ErroCalculo::MesInvalido(13)
+        );
+    }
+
+    #[test]
+    fn test_calcular_valores_com_calendario_e_viagens() {
+        let resultado = calcular_valores_com_calendario_e_viagens(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            11, // Novembro
+            2024,
+            2,
+            4,
+            vec![],
+        )
+        .unwrap();
+
+        assert_eq!(resultado.viagens_por_dia, 4);
+        // 7.50 * 19 * 4 = 570.00
+        assert_eq!(resultado.custo_transporte, Money::from_reais(570));
+        assert_eq!(resultado.pagamento_final, Money::from_reais(720));
+        assert_eq!(resultado.calendario.unwrap().nome_mes, Some("Novembro"));
+        assert_eq!(
+            calcular_valores_com_calendario_e_viagens(
+                Money::ZERO,
+                Money::ZERO,
+                13,
+                2024,
+                0,
+                1,
+                vec![]
+            )
+            .unwrap_err(),
+            ErroCalculo::MesInvalido(13)
+        );
+    }
+
+    #[test]
+    fn test_calcular_valores_com_dias_uteis_igual_ao_calendario() {
+        let deducoes = vec![Deducao {
+            valor: Money::from_reais(25),
+            descricao: "adiantamento".to_string(),
+        }];
+        let taxa = Money::parse("7.50").unwrap();
+
+        let pelo_calendario = calcular_valores_com_calendario(
+            Money::from_reais(150),
+            taxa,
+            11,
+            2024,
+            2,
+            deducoes.clone(),
+        )
+        .unwrap();
+        let ja_contado =
+            calcular_valores_com_dias_uteis(Money::from_reais(150), taxa, 21, 2, deducoes);
+
+        assert_eq!(
+            ja_contado.dias_trabalhados,
+            pelo_calendario.dias_trabalhados
+        );
+        assert_eq!(
+            ja_contado.custo_transporte,
+            pelo_calendario.custo_transporte
+        );
+        assert_eq!(ja_contado.pagamento_final, pelo_calendario.pagamento_final);
+
+        let calendario = ja_contado.calendario.unwrap();
+        assert_eq!(calendario.mes, None);
+        assert_eq!(calendario.ano, None);
+        assert_eq!(calendario.nome_mes, None);
         assert_eq!(calendario.dias_uteis_mes, 21);
         assert_eq!(calendario.feriados_deduzidos, 2);
         assert_eq!(calendario.dias_trabalhados, 19);
     }
 
+    #[test]
+    fn test_calcular_valores_com_dias_uteis_feriados_excessivos() {
+        let resultado = calcular_valores_com_dias_uteis(
+            Money::from_reais(100),
+            Money::from_reais(10),
+            5,
+            8,
+            vec![],
+        );
+
+        assert_eq!(resultado.dias_trabalhados, 0);
+        assert_eq!(resultado.pagamento_final, Money::from_reais(100));
+    }
+
     #[test]
     fn test_calcular_valores_com_feriados_excessivos() {
         let resultado = calcular_valores_com_calendario(
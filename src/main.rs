@@ -1,5 +1,25 @@
-use calculadora::calcular_pagamento;
+use calculadora::{
+    calcular_pagamento_com_padrao, ler_argumentos, ler_config_parcial, ConfiguracaoPadrao,
+    OpcoesCli,
+};
+use std::io::IsTerminal;
 
 fn main() {
-    calcular_pagamento();
+    // Cores só fazem sentido no terminal; em pipes a saída fica limpa
+    let opcoes = OpcoesCli {
+        cores: std::env::args().skip(1).any(|arg| arg == "--cores")
+            && std::io::stdout().is_terminal(),
+        brl: std::env::args().skip(1).any(|arg| arg == "--brl"),
+    };
+
+    // Variáveis CALC_* valem como padrão; os argumentos têm precedência
+    let resultado = ler_argumentos(std::env::args().skip(1)).and_then(|argumentos| {
+        let ambiente = ler_config_parcial(&std::env::vars().collect())?;
+        let padrao = ConfiguracaoPadrao::carregar_do_usuario()?;
+        calcular_pagamento_com_padrao(&argumentos.com_padroes_de(ambiente), &padrao, opcoes)
+    });
+    if let Err(erro) = resultado {
+        eprintln!("Erro: {}", erro);
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,226 @@
+//! Formatos de `Money` para serde, escolhidos por campo com
+//! `#[serde(with = "calculadora::money_serde::texto")]`:
+//!
+//! - `centavos`: inteiro de centavos (12345), o padrão de `Money`;
+//! - `texto`: string com duas casas ("123.45");
+//! - `numero`: número em reais (123.45).
+//!
+//! Na leitura os três aceitam texto no formato de `Money::parse`; números
+//! são lidos na unidade do formato (centavos em `centavos`, reais nos
+//! outros dois) e frações de centavo são rejeitadas.
+
+use crate::{ErroParseMoney, Money};
+use serde::de::{self, Visitor};
+use serde::{Deserializer, Serializer};
+use std::fmt;
+
+#[derive(Clone, Copy)]
+enum Unidade {
+    Centavos,
+    Reais,
+}
+
+struct MoneyVisitor(Unidade);
+
+impl<'de> Visitor<'de> for MoneyVisitor {
+    type Value = Money;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "um valor monetário em número ou texto")
+    }
+
+    fn visit_i64<E: de::Error>(self, valor: i64) -> Result<Money, E> {
+        match self.0 {
+            Unidade::Centavos => Ok(Money::from_centavos(valor)),
+            Unidade::Reais => valor
+                .checked_mul(100)
+                .map(Money::from_centavos)
+                .ok_or_else(|| E::custom(ErroParseMoney::ParteReaisInvalida)),
+        }
+    }
+
+    fn visit_u64<E: de::Error>(self, valor: u64) -> Result<Money, E> {
+        let valor =
+            i64::try_from(valor).map_err(|_| E::custom(ErroParseMoney::ParteReaisInvalida))?;
+        self.visit_i64(valor)
+    }
+
+    fn visit_f64<E: de::Error>(self, valor: f64) -> Result<Money, E> {
+        match self.0 {
+            Unidade::Centavos if valor.fract() == 0.0 && valor.abs() < i64::MAX as f64 => {
+                Ok(Money::from_centavos(valor as i64))
+            }
+            Unidade::Centavos => Err(E::custom(ErroParseMoney::MuitasCasasDecimais)),
+            // A representação mais curta do f64 ("123.45") é a que o
+            // consumidor escreveu; mais de duas casas é fração de centavo
+            Unidade::Reais => {
+                let texto = valor.to_string();
+                match texto.split_once('.') {
+                    Some((_, casas)) if casas.len() > 2 => {
+                        Err(E::custom(ErroParseMoney::MuitasCasasDecimais))
+                    }
+                    _ => Money::parse(&texto).map_err(E::custom),
+                }
+            }
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, valor: &str) -> Result<Money, E> {
+        Money::parse(valor).map_err(E::custom)
+    }
+}
+
+/// Inteiro de centavos (12345 para R$ 123,45).
+pub mod centavos {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(valor: &Money, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(valor.to_centavos())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Money, D::Error> {
+        deserializer.deserialize_any(MoneyVisitor(Unidade::Centavos))
+    }
+}
+
+/// String com duas casas ("123.45"), sem perda de precisão.
+pub mod texto {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(valor: &Money, serializer: S) -> Result<S::Ok, S::Error> {
+        // O sinal vem à parte para que -0.50 não perca o "-" nos reais
+        let centavos = valor.to_centavos();
+        let sinal = if centavos < 0 { "-" } else { "" };
+        let texto = format!(
+            "{}{}.{:02}",
+            sinal,
+            (centavos / 100).abs(),
+            (centavos % 100).abs()
+        );
+        serializer.serialize_str(&texto)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Money, D::Error> {
+        deserializer.deserialize_any(MoneyVisitor(Unidade::Reais))
+    }
+}
+
+/// Número em reais (123.45). Passa por `f64`, então só é exato até
+/// 2^53 centavos.
+pub mod numero {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(valor: &Money, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(valor.to_centavos() as f64 / 100.0)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Money, D::Error> {
+        deserializer.deserialize_any(MoneyVisitor(Unidade::Reais))
+    }
+}
+
+impl serde::Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        centavos::serialize(self, serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Money, D::Error> {
+        centavos::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Valores {
+        padrao: Money,
+        #[serde(with = "super::centavos")]
+        centavos: Money,
+        #[serde(with = "super::texto")]
+        texto: Money,
+        #[serde(with = "super::numero")]
+        numero: Money,
+    }
+
+    fn valores(valor: Money) -> Valores {
+        Valores {
+            padrao: valor,
+            centavos: valor,
+            texto: valor,
+            numero: valor,
+        }
+    }
+
+    #[test]
+    fn test_round_trip_em_cada_formato() {
+        for texto in ["123.45", "-20.50", "0.01", "0.00", "1234567.89"] {
+            let original = valores(Money::parse(texto).unwrap());
+
+            let json = serde_json::to_string(&original).unwrap();
+
+            assert_eq!(serde_json::from_str::<Valores>(&json).unwrap(), original);
+        }
+    }
+
+    #[test]
+    fn test_formato_serializado() {
+        let json = serde_json::to_string(&valores(Money::parse("123.45").unwrap())).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"padrao":12345,"centavos":12345,"texto":"123.45","numero":123.45}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&valores(Money::from_centavos(-50))).unwrap(),
+            r#"{"padrao":-50,"centavos":-50,"texto":"-0.50","numero":-0.5}"#
+        );
+    }
+
+    #[test]
+    fn test_desserializa_texto_em_qualquer_formato() {
+        let json = r#"{"padrao":"7.50","centavos":"R$ 7.50","texto":"7.5","numero":"(7.50)"}"#;
+
+        let lido = serde_json::from_str::<Valores>(json).unwrap();
+
+        assert_eq!(lido.padrao, Money::from_centavos(750));
+        assert_eq!(lido.centavos, Money::from_centavos(750));
+        assert_eq!(lido.texto, Money::from_centavos(750));
+        assert_eq!(lido.numero, Money::from_centavos(-750));
+    }
+
+    #[test]
+    fn test_desserializa_numeros_na_unidade_do_formato() {
+        let json = r#"{"padrao":750,"centavos":750.0,"texto":7.5,"numero":7}"#;
+
+        let lido = serde_json::from_str::<Valores>(json).unwrap();
+
+        assert_eq!(lido.padrao, Money::from_centavos(750));
+        assert_eq!(lido.centavos, Money::from_centavos(750));
+        assert_eq!(lido.texto, Money::from_centavos(750));
+        assert_eq!(lido.numero, Money::from_reais(7));
+    }
+
+    #[test]
+    fn test_rejeita_fracao_de_centavo() {
+        assert!(serde_json::from_str::<Money>("7.5").is_err());
+        assert!(serde_json::from_str::<Money>("\"7,505\"").is_err());
+        let json = r#"{"padrao":1,"centavos":1,"texto":"1","numero":1.234}"#;
+        assert!(serde_json::from_str::<Valores>(json).is_err());
+        assert!(serde_json::from_str::<Money>("true").is_err());
+    }
+
+    #[test]
+    fn test_erros_usam_mensagens_de_erro_parse_money() {
+        let erro = serde_json::from_str::<Money>("7.5").unwrap_err();
+        assert!(erro.to_string().starts_with("Casas decimais demais"));
+
+        let json = r#"{"padrao":1,"centavos":1,"texto":"abc","numero":1}"#;
+        let erro = serde_json::from_str::<Valores>(json).unwrap_err();
+        assert!(erro.to_string().starts_with("Número inválido"));
+    }
+}
@@ -0,0 +1,167 @@
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use std::collections::BTreeMap;
+
+use crate::{listar_dias_uteis, ErroCalculo};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResumoPonto {
+    pub dias_trabalhados: i32,
+    pub minutos_trabalhados: i64,
+    pub datas_trabalhadas: Vec<NaiveDate>, // dias com batida, em ordem
+    pub faltas: Vec<NaiveDate>,            // dias úteis sem batida, em ordem
+}
+
+impl ResumoPonto {
+    pub fn horas_trabalhadas(&self) -> f64 {
+        self.minutos_trabalhados as f64 / 60.0
+    }
+}
+
+/// Resume as batidas de (data, entrada, saída) da competência mes/ano.
+/// Cada data com ao menos uma batida conta como um dia trabalhado, e as
+/// horas de todas as batidas do dia são somadas. Saída antes da entrada é
+/// um turno que atravessa a meia-noite. Batidas de outros meses são
+/// ignoradas. Faltas são os dias de `listar_dias_uteis` sem nenhuma
+/// batida, inclusive antes da primeira e depois da última. As
+/// `datas_trabalhadas` alimentam `calcular_por_datas`.
+pub fn processar_ponto(
+    batidas: &[(NaiveDate, NaiveTime, NaiveTime)],
+    mes: u32,
+    ano: i32,
+) -> Result<ResumoPonto, ErroCalculo> {
+    let dias_uteis = listar_dias_uteis(mes, ano)?;
+    let mut minutos_por_dia: BTreeMap<NaiveDate, i64> = BTreeMap::new();
+
+    for &(data, entrada, saida) in batidas {
+        if data.month() != mes || data.year() != ano {
+            continue;
+        }
+        let mut minutos = (saida - entrada).num_minutes();
+        if minutos < 0 {
+            minutos += 24 * 60;
+        }
+        *minutos_por_dia.entry(data).or_insert(0) += minutos;
+    }
+
+    let faltas = dias_uteis
+        .into_iter()
+        .filter(|data| !minutos_por_dia.contains_key(data))
+        .collect();
+
+    Ok(ResumoPonto {
+        dias_trabalhados: minutos_por_dia.len() as i32,
+        minutos_trabalhados: minutos_por_dia.values().sum(),
+        datas_trabalhadas: minutos_por_dia.keys().copied().collect(),
+        faltas,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calcular_por_datas, Money};
+
+    fn data(dia: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, dia).unwrap()
+    }
+
+    fn hora(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn test_processar_ponto_conta_dias_horas_e_faltas() {
+        // 04/03/2024 é segunda; quarta (06) fica sem batida e o fim de
+        // semana depois de sexta (08) não conta como falta. Os dias úteis
+        // sem batida antes (01) e depois (12 em diante) também são faltas
+        let batidas = [
+            (data(4), hora(8, 0), hora(12, 0)),
+            (data(4), hora(13, 0), hora(17, 30)),
+            (data(5), hora(9, 0), hora(18, 0)),
+            (data(7), hora(8, 0), hora(16, 0)),
+            (data(8), hora(8, 0), hora(12, 0)),
+            (data(11), hora(8, 0), hora(17, 0)),
+        ];
+
+        let resumo = processar_ponto(&batidas, 3, 2024).unwrap();
+
+        assert_eq!(resumo.dias_trabalhados, 5);
+        assert_eq!(
+            resumo.minutos_trabalhados,
+            (8 * 60 + 30) + 9 * 60 + 8 * 60 + 4 * 60 + 9 * 60
+        );
+        assert_eq!(resumo.horas_trabalhadas(), 38.5);
+        let mut faltas = vec![data(1), data(6)];
+        faltas.extend([12, 13, 14, 15, 18, 19, 20, 21, 22, 25, 26, 27, 28, 29].map(data));
+        assert_eq!(resumo.faltas, faltas);
+        assert_eq!(
+            resumo.datas_trabalhadas,
+            vec![data(4), data(5), data(7), data(8), data(11)]
+        );
+
+        let resultado = calcular_por_datas(
+            Money::from_reais(1000),
+            Money::from_reais(5),
+            &resumo.datas_trabalhadas,
+            vec![],
+        );
+        assert_eq!(resultado.dias_trabalhados, resumo.dias_trabalhados);
+        assert_eq!(resultado.custo_transporte, Money::from_reais(50));
+    }
+
+    #[test]
+    fn test_processar_ponto_turno_noturno() {
+        let resumo = processar_ponto(&[(data(4), hora(22, 0), hora(6, 0))], 3, 2024).unwrap();
+
+        assert_eq!(resumo.dias_trabalhados, 1);
+        assert_eq!(resumo.minutos_trabalhados, 8 * 60);
+        assert!(!resumo.faltas.contains(&data(4)));
+    }
+
+    #[test]
+    fn test_processar_ponto_sem_batidas() {
+        let resumo = processar_ponto(&[], 3, 2024).unwrap();
+
+        assert_eq!(resumo.dias_trabalhados, 0);
+        assert_eq!(resumo.minutos_trabalhados, 0);
+        assert_eq!(resumo.faltas, listar_dias_uteis(3, 2024).unwrap());
+    }
+
+    #[test]
+    fn test_processar_ponto_falta_no_primeiro_dia_util() {
+        // 01/03/2024 é sexta; todos os outros dias úteis têm batida
+        let batidas: Vec<_> = listar_dias_uteis(3, 2024)
+            .unwrap()
+            .into_iter()
+            .skip(1)
+            .map(|dia| (dia, hora(8, 0), hora(17, 0)))
+            .collect();
+
+        let resumo = processar_ponto(&batidas, 3, 2024).unwrap();
+
+        assert_eq!(resumo.dias_trabalhados, 20);
+        assert_eq!(resumo.faltas, vec![data(1)]);
+    }
+
+    #[test]
+    fn test_processar_ponto_ignora_outros_meses() {
+        let batidas = [
+            (
+                NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+                hora(8, 0),
+                hora(17, 0),
+            ),
+            (data(4), hora(8, 0), hora(17, 0)),
+        ];
+
+        let resumo = processar_ponto(&batidas, 3, 2024).unwrap();
+
+        assert_eq!(resumo.dias_trabalhados, 1);
+        assert_eq!(resumo.minutos_trabalhados, 9 * 60);
+    }
+
+    #[test]
+    fn test_processar_ponto_mes_invalido() {
+        assert!(processar_ponto(&[], 13, 2024).is_err());
+    }
+}
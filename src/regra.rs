@@ -0,0 +1,84 @@
+use crate::{EntradaCalculo, ErroCalculo, ResultadoCalculo};
+
+/// Fórmula que transforma a entrada no resultado. Empresas com regras
+/// próprias (INSS descontado, teto de transporte) implementam o trait em
+/// vez de alterar a crate.
+pub trait RegraCalculo {
+    fn calcular(&self, entrada: &EntradaCalculo) -> Result<ResultadoCalculo, ErroCalculo>;
+}
+
+/// Fórmula atual (taxa fixa + transporte - deduções), a mesma de
+/// `EntradaCalculo::calcular`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RegraPadrao;
+
+impl RegraCalculo for RegraPadrao {
+    fn calcular(&self, entrada: &EntradaCalculo) -> Result<ResultadoCalculo, ErroCalculo> {
+        entrada.calcular()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calcular_inss, Deducao, Money, TabelaINSS};
+
+    fn entrada() -> EntradaCalculo {
+        EntradaCalculo {
+            nome: String::new(),
+            taxa_fixa: Money::from_reais(2000),
+            taxa_transporte: Money::parse("7.50").unwrap(),
+            mes: 11,
+            ano: 2024,
+            feriados_deduzidos: 0,
+            deducoes: vec![],
+        }
+    }
+
+    // Desconta o INSS sobre a taxa fixa além das deduções informadas
+    struct ComINSS;
+
+    impl RegraCalculo for ComINSS {
+        fn calcular(&self, entrada: &EntradaCalculo) -> Result<ResultadoCalculo, ErroCalculo> {
+            let mut entrada = entrada.clone();
+            entrada.deducoes.push(Deducao {
+                valor: calcular_inss(entrada.taxa_fixa, &TabelaINSS::tabela_2024()),
+                descricao: "INSS".to_string(),
+            });
+            RegraPadrao.calcular(&entrada)
+        }
+    }
+
+    #[test]
+    fn test_regra_padrao_igual_ao_calculo_atual() {
+        let resultado = RegraPadrao.calcular(&entrada()).unwrap();
+
+        // 2000 + 21 × 2 × 7.50
+        assert_eq!(resultado.pagamento_final, Money::from_reais(2315));
+        assert_eq!(
+            RegraPadrao
+                .calcular(&EntradaCalculo {
+                    mes: 0,
+                    ..entrada()
+                })
+                .unwrap_err(),
+            ErroCalculo::MesInvalido(0)
+        );
+    }
+
+    #[test]
+    fn test_regra_customizada() {
+        let regras: [&dyn RegraCalculo; 2] = [&RegraPadrao, &ComINSS];
+        let resultados: Vec<ResultadoCalculo> = regras
+            .iter()
+            .map(|regra| regra.calcular(&entrada()).unwrap())
+            .collect();
+
+        // INSS de 2000.00 = 158.82
+        assert_eq!(
+            resultados[0].pagamento_final - resultados[1].pagamento_final,
+            Money::parse("158.82").unwrap()
+        );
+        assert_eq!(resultados[1].deducoes[0].descricao, "INSS");
+    }
+}
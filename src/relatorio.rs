@@ -0,0 +1,1252 @@
+use crate::{obter_nome_mes_lang, Idioma, Money, ResultadoCalculo};
+use std::fmt::Write;
+
+const NEGRITO: &str = "\x1b[1m";
+const VERDE: &str = "\x1b[32m";
+const VERMELHO: &str = "\x1b[31m";
+const RESET: &str = "\x1b[0m";
+
+pub fn formatar_resultado(resultado: &ResultadoCalculo) -> String {
+    montar_resumo(resultado, false, false, &TEXTOS_PT)
+}
+
+pub fn formatar_resultado_colorido(resultado: &ResultadoCalculo) -> String {
+    montar_resumo(resultado, true, false, &TEXTOS_PT)
+}
+
+/// Mesmo resumo com os valores no padrão brasileiro (R$ 1.234,56).
+pub fn formatar_resultado_brl(resultado: &ResultadoCalculo, cores: bool) -> String {
+    montar_resumo(resultado, cores, true, &TEXTOS_PT)
+}
+
+/// Mesmo resumo com rótulos e nome do mês no idioma pedido. Nomes de
+/// feriados e descrições de proventos e deduções ficam como vieram.
+pub fn formatar_resultado_em_idioma(
+    resultado: &ResultadoCalculo,
+    idioma: Idioma,
+    cores: bool,
+) -> String {
+    let textos = match idioma {
+        Idioma::Pt => &TEXTOS_PT,
+        Idioma::En => &TEXTOS_EN,
+    };
+    montar_resumo(resultado, cores, false, textos)
+}
+
+// Rótulos do resumo; os pares são (singular, plural)
+struct Textos {
+    idioma: Idioma,
+    titulo: &'static str,
+    taxa_fixa: &'static str,
+    mes_ano: &'static str,
+    dias_uteis: &'static str,
+    feriados: &'static str,
+    feriado: &'static str,
+    nao_deduzido: &'static str,
+    dias_trabalhados: &'static str,
+    taxa_transporte: &'static str,
+    custo_transporte: &'static str,
+    proventos: &'static str,
+    total_proventos: &'static str,
+    deducoes: &'static str,
+    total_deducoes: &'static str,
+    pagamento_final: &'static str,
+    dias: &'static str,
+    dias_inteiros: &'static str,
+    viagem: (&'static str, &'static str),
+    meio_dia: (&'static str, &'static str),
+    separador_decimal: char,
+}
+
+const TEXTOS_PT: Textos = Textos {
+    idioma: Idioma::Pt,
+    titulo: "RESUMO DO PAGAMENTO",
+    taxa_fixa: "Taxa fixa:",
+    mes_ano: "Mês/Ano:",
+    dias_uteis: "Dias úteis no mês:",
+    feriados: "Feriados/dias não trabalhados:",
+    feriado: "Feriado",
+    nao_deduzido: "fim de semana, não deduzido",
+    dias_trabalhados: "Dias trabalhados:",
+    taxa_transporte: "Taxa de transporte por viagem:",
+    custo_transporte: "Custo total do transporte:",
+    proventos: "Proventos:",
+    total_proventos: "Total de proventos:",
+    deducoes: "Deduções:",
+    total_deducoes: "Total de deduções:",
+    pagamento_final: "PAGAMENTO FINAL:",
+    dias: "dias",
+    dias_inteiros: "dias inteiros",
+    viagem: ("viagem", "viagens"),
+    meio_dia: ("meio-dia", "meios-dias"),
+    separador_decimal: ',',
+};
+
+const TEXTOS_EN: Textos = Textos {
+    idioma: Idioma::En,
+    titulo: "PAYMENT SUMMARY",
+    taxa_fixa: "Fixed rate:",
+    mes_ano: "Month/Year:",
+    dias_uteis: "Working days in month:",
+    feriados: "Holidays/days off:",
+    feriado: "Holiday",
+    nao_deduzido: "weekend, not deducted",
+    dias_trabalhados: "Days worked:",
+    taxa_transporte: "Transport fare per trip:",
+    custo_transporte: "Total transport cost:",
+    proventos: "Earnings:",
+    total_proventos: "Total earnings:",
+    deducoes: "Deductions:",
+    total_deducoes: "Total deductions:",
+    pagamento_final: "FINAL PAYMENT:",
+    dias: "days",
+    dias_inteiros: "full days",
+    viagem: ("trip", "trips"),
+    meio_dia: ("half day", "half days"),
+    separador_decimal: '.',
+};
+
+fn rotulo(texto: &str, cores: bool) -> String {
+    if cores {
+        format!("{}{}{}", NEGRITO, texto, RESET)
+    } else {
+        texto.to_string()
+    }
+}
+
+fn valor_final(valor: Money, texto: String, cores: bool) -> String {
+    if !cores {
+        return texto;
+    }
+
+    let cor = if valor < Money::ZERO { VERMELHO } else { VERDE };
+    format!("{}{}{}{}", NEGRITO, cor, texto, RESET)
+}
+
+// "18", "18,5", "18,25": até duas casas, sem zeros à direita
+fn formatar_numero_dias(dias: f64, separador_decimal: char) -> String {
+    let texto = format!("{:.2}", dias);
+    let texto = texto.trim_end_matches('0').trim_end_matches('.');
+    texto.replace('.', &separador_decimal.to_string())
+}
+
+// "18 dias", "18,5 dias", "18.5 days"
+fn formatar_dias(dias: f64, unidade: &str, textos: &Textos) -> String {
+    format!(
+        "{} {}",
+        formatar_numero_dias(dias, textos.separador_decimal),
+        unidade
+    )
+}
+
+// "1 viagem", "4 viagens"
+fn formatar_contagem(quantidade: i32, (singular, plural): (&str, &str)) -> String {
+    if quantidade == 1 {
+        return format!("1 {}", singular);
+    }
+    format!("{} {}", quantidade, plural)
+}
+
+fn montar_resumo(
+    resultado: &ResultadoCalculo,
+    cores: bool,
+    formato_brl: bool,
+    textos: &Textos,
+) -> String {
+    let reais = |valor: Money| {
+        if formato_brl {
+            valor.format_brl_com_simbolo()
+        } else {
+            format!("R$ {}", valor)
+        }
+    };
+    let mut saida = String::new();
+
+    writeln!(saida, "{}", "=".repeat(40)).unwrap();
+    writeln!(saida, "{}", rotulo(textos.titulo, cores)).unwrap();
+    writeln!(saida, "{}", "=".repeat(40)).unwrap();
+    writeln!(
+        saida,
+        "{} {}",
+        rotulo(textos.taxa_fixa, cores),
+        reais(resultado.taxa_fixa)
+    )
+    .unwrap();
+    for (descricao, valor) in &resultado.componentes_fixos {
+        writeln!(saida, "  {}: {}", descricao, reais(*valor)).unwrap();
+    }
+
+    // Informações do calendário
+    if let Some(calendario) = &resultado.calendario {
+        if let (Some(mes), Some(ano)) = (calendario.mes, calendario.ano) {
+            let nome_mes = obter_nome_mes_lang(mes, textos.idioma);
+            writeln!(
+                saida,
+                "{} {} {}",
+                rotulo(textos.mes_ano, cores),
+                nome_mes,
+                ano
+            )
+            .unwrap();
+        }
+        writeln!(
+            saida,
+            "{} {}",
+            rotulo(textos.dias_uteis, cores),
+            calendario.dias_uteis_mes
+        )
+        .unwrap();
+        if calendario.feriados_deduzidos > 0 {
+            writeln!(
+                saida,
+                "{} {}",
+                rotulo(textos.feriados, cores),
+                calendario.feriados_deduzidos
+            )
+            .unwrap();
+        }
+        for feriado in &calendario.feriados {
+            let data = feriado.data.format("%d/%m");
+            let nome = feriado.nome.as_deref().unwrap_or(textos.feriado);
+            if feriado.deduzido {
+                writeln!(saida, "  {} {}", data, nome).unwrap();
+            } else {
+                writeln!(saida, "  {} {} ({})", data, nome, textos.nao_deduzido).unwrap();
+            }
+        }
+        writeln!(
+            saida,
+            "{} {}",
+            rotulo(textos.dias_trabalhados, cores),
+            calendario.dias_trabalhados
+        )
+        .unwrap();
+    }
+
+    writeln!(
+        saida,
+        "{} {}",
+        rotulo(textos.taxa_transporte, cores),
+        reais(resultado.taxa_transporte)
+    )
+    .unwrap();
+    writeln!(
+        saida,
+        "{} {}",
+        rotulo(textos.custo_transporte, cores),
+        reais(resultado.custo_transporte)
+    )
+    .unwrap();
+    if resultado.meios_dias > 0 {
+        writeln!(
+            saida,
+            "  ({} × {} × {} + {} × {} × {})",
+            formatar_dias(
+                resultado.dias_trabalhados as f64,
+                textos.dias_inteiros,
+                textos
+            ),
+            reais(resultado.taxa_transporte),
+            formatar_contagem(resultado.viagens_por_dia, textos.viagem),
+            formatar_contagem(resultado.meios_dias, textos.meio_dia),
+            reais(resultado.taxa_transporte),
+            formatar_contagem(1, textos.viagem)
+        )
+        .unwrap();
+    } else {
+        writeln!(
+            saida,
+            "  ({} × {} × {})",
+            formatar_dias(resultado.dias_trabalhados_fracionarios, textos.dias, textos),
+            reais(resultado.taxa_transporte),
+            formatar_contagem(resultado.viagens_por_dia, textos.viagem)
+        )
+        .unwrap();
+    }
+
+    if !resultado.proventos.is_empty() {
+        writeln!(saida, "\n{}", rotulo(textos.proventos, cores)).unwrap();
+        for provento in &resultado.proventos {
+            if provento.descricao.is_empty() {
+                writeln!(saida, "  + {}", reais(provento.valor)).unwrap();
+            } else {
+                writeln!(
+                    saida,
+                    "  + {} ({})",
+                    reais(provento.valor),
+                    provento.descricao
+                )
+                .unwrap();
+            }
+        }
+        writeln!(
+            saida,
+            "{} {}",
+            rotulo(textos.total_proventos, cores),
+            reais(resultado.proventos_total)
+        )
+        .unwrap();
+    }
+
+    if !resultado.deducoes.is_empty() {
+        writeln!(saida, "\n{}", rotulo(textos.deducoes, cores)).unwrap();
+        for deducao in &resultado.deducoes {
+            if deducao.descricao.is_empty() {
+                writeln!(saida, "  - {}", reais(deducao.valor)).unwrap();
+            } else {
+                writeln!(
+                    saida,
+                    "  - {} ({})",
+                    reais(deducao.valor),
+                    deducao.descricao
+                )
+                .unwrap();
+            }
+        }
+        writeln!(
+            saida,
+            "{} {}",
+            rotulo(textos.total_deducoes, cores),
+            reais(resultado.deducoes_total)
+        )
+        .unwrap();
+    }
+
+    writeln!(saida, "{}", "-".repeat(40)).unwrap();
+    writeln!(
+        saida,
+        "{} {}",
+        rotulo(textos.pagamento_final, cores),
+        valor_final(
+            resultado.pagamento_final,
+            reais(resultado.pagamento_final),
+            cores
+        )
+    )
+    .unwrap();
+    writeln!(saida, "{}", "=".repeat(40)).unwrap();
+
+    saida
+}
+
+/// Resumo curto e informal para colar em apps de chat.
+pub fn resumo_chat(resultado: &ResultadoCalculo) -> String {
+    let mut saida = String::new();
+
+    writeln!(
+        saida,
+        "💰 Pagamento: {}",
+        resultado.pagamento_final.format_brl_com_simbolo()
+    )
+    .unwrap();
+    writeln!(
+        saida,
+        "🚌 Transporte: {}",
+        resultado.custo_transporte.format_brl_com_simbolo()
+    )
+    .unwrap();
+    write!(
+        saida,
+        "📅 Dias: {}",
+        formatar_numero_dias(resultado.dias_trabalhados_fracionarios, ',')
+    )
+    .unwrap();
+
+    saida
+}
+
+// Quebra por palavras; palavras maiores que a largura são cortadas
+fn quebrar(texto: &str, largura: usize) -> Vec<String> {
+    let largura = largura.max(1);
+    let mut linhas = Vec::new();
+    let mut atual = String::new();
+
+    for palavra in texto.split_whitespace() {
+        let mut palavra: Vec<char> = palavra.chars().collect();
+        while palavra.len() > largura {
+            if !atual.is_empty() {
+                linhas.push(std::mem::take(&mut atual));
+            }
+            linhas.push(palavra.drain(..largura).collect());
+        }
+        if palavra.is_empty() {
+            continue;
+        }
+
+        let tamanho_atual = atual.chars().count();
+        if tamanho_atual > 0 && tamanho_atual + 1 + palavra.len() > largura {
+            linhas.push(std::mem::take(&mut atual));
+        }
+        if !atual.is_empty() {
+            atual.push(' ');
+        }
+        atual.extend(palavra);
+    }
+
+    if !atual.is_empty() {
+        linhas.push(atual);
+    }
+    linhas
+}
+
+// Rótulo à esquerda e valor à direita; se não couberem juntos, o rótulo
+// é quebrado e o valor vai alinhado na última linha ou sozinho
+fn linha_recibo(saida: &mut String, rotulo: &str, valor: &str, largura: usize) {
+    let tamanho_valor = valor.chars().count();
+    let mut linhas = quebrar(rotulo, largura);
+    let ultima = linhas.pop().unwrap_or_default();
+
+    for linha in linhas {
+        writeln!(saida, "{}", linha).unwrap();
+    }
+
+    let tamanho_ultima = ultima.chars().count();
+    if tamanho_ultima + 1 + tamanho_valor <= largura {
+        let espacos = largura - tamanho_ultima - tamanho_valor;
+        writeln!(saida, "{}{}{}", ultima, " ".repeat(espacos), valor).unwrap();
+        return;
+    }
+
+    if !ultima.is_empty() {
+        writeln!(saida, "{}", ultima).unwrap();
+    }
+    for parte in quebrar(valor, largura) {
+        writeln!(saida, "{:>largura$}", parte).unwrap();
+    }
+}
+
+/// Recibo em largura fixa para impressoras de cupom: título centralizado,
+/// valores alinhados à direita e nenhuma linha maior que `largura`.
+pub fn gerar_recibo(resultado: &ResultadoCalculo, largura: usize) -> String {
+    let mut saida = String::new();
+    let separador = |c: &str| c.repeat(largura);
+
+    writeln!(saida, "{}", separador("=")).unwrap();
+    for linha in quebrar("RECIBO DE PAGAMENTO", largura) {
+        writeln!(saida, "{:^largura$}", linha).unwrap();
+    }
+    if let Some(calendario) = &resultado.calendario {
+        if let (Some(nome_mes), Some(ano)) = (calendario.nome_mes, calendario.ano) {
+            for linha in quebrar(&format!("{} {}", nome_mes, ano), largura) {
+                writeln!(saida, "{:^largura$}", linha).unwrap();
+            }
+        }
+    }
+    writeln!(saida, "{}", separador("=")).unwrap();
+
+    let reais = |valor: Money| format!("R$ {}", valor);
+    linha_recibo(
+        &mut saida,
+        "Taxa fixa",
+        &reais(resultado.taxa_fixa),
+        largura,
+    );
+    linha_recibo(
+        &mut saida,
+        &format!(
+            "Transporte ({})",
+            formatar_dias(resultado.dias_trabalhados_fracionarios, "dias", &TEXTOS_PT)
+        ),
+        &reais(resultado.custo_transporte),
+        largura,
+    );
+    for provento in &resultado.proventos {
+        let rotulo = if provento.descricao.is_empty() {
+            "Provento"
+        } else {
+            &provento.descricao
+        };
+        linha_recibo(&mut saida, rotulo, &reais(provento.valor), largura);
+    }
+    for deducao in &resultado.deducoes {
+        let rotulo = if deducao.descricao.is_empty() {
+            "Dedução"
+        } else {
+            &deducao.descricao
+        };
+        linha_recibo(
+            &mut saida,
+            rotulo,
+            &format!("-R$ {}", deducao.valor),
+            largura,
+        );
+    }
+
+    writeln!(saida, "{}", separador("-")).unwrap();
+    linha_recibo(
+        &mut saida,
+        "TOTAL",
+        &reais(resultado.pagamento_final),
+        largura,
+    );
+    writeln!(saida, "{}", separador("=")).unwrap();
+
+    saida
+}
+
+type Coluna = Vec<(String, String)>;
+
+fn largura_coluna(coluna: &Coluna) -> (usize, usize) {
+    coluna.iter().fold((0, 0), |(rotulo, valor), (r, v)| {
+        (rotulo.max(r.chars().count()), valor.max(v.chars().count()))
+    })
+}
+
+fn celula(item: Option<&(String, String)>, (rotulo, valor): (usize, usize)) -> String {
+    match item {
+        Some((r, v)) => format!("{:<rotulo$} {:>valor$}", r, v),
+        None => " ".repeat(rotulo + 1 + valor),
+    }
+}
+
+/// Resumo no formato de holerite: proventos à esquerda, descontos à
+/// direita, totais de cada coluna no rodapé e o líquido no final.
+pub fn resultado_duas_colunas(resultado: &ResultadoCalculo) -> String {
+    let item = |descricao: &str, padrao: &str, valor: Money| {
+        let rotulo = if descricao.is_empty() {
+            padrao
+        } else {
+            descricao
+        };
+        (rotulo.to_string(), format!("R$ {}", valor))
+    };
+
+    let mut proventos: Coluna = vec![
+        item("Taxa fixa", "", resultado.taxa_fixa),
+        item("Transporte", "", resultado.custo_transporte),
+    ];
+    proventos.extend(
+        resultado
+            .proventos
+            .iter()
+            .map(|p| item(&p.descricao, "Provento", p.valor)),
+    );
+    let descontos: Coluna = resultado
+        .deducoes
+        .iter()
+        .map(|d| item(&d.descricao, "Dedução", d.valor))
+        .collect();
+
+    let total_proventos =
+        resultado.taxa_fixa + resultado.custo_transporte + resultado.proventos_total;
+    let total_esquerda = item("Total proventos", "", total_proventos);
+    let total_direita = item("Total descontos", "", resultado.deducoes_total);
+
+    // Larguras consideram também os títulos e as linhas de total
+    let mut medida_esquerda = proventos.clone();
+    medida_esquerda.extend([
+        ("PROVENTOS".to_string(), String::new()),
+        total_esquerda.clone(),
+    ]);
+    let mut medida_direita = descontos.clone();
+    medida_direita.extend([
+        ("DESCONTOS".to_string(), String::new()),
+        total_direita.clone(),
+    ]);
+    let esquerda = largura_coluna(&medida_esquerda);
+    let direita = largura_coluna(&medida_direita);
+    let largura_esquerda = esquerda.0 + 1 + esquerda.1;
+    let largura_direita = direita.0 + 1 + direita.1;
+    let largura_total = largura_esquerda + 3 + largura_direita;
+
+    let mut saida = String::new();
+    writeln!(
+        saida,
+        "{:<largura_esquerda$} | {:<largura_direita$}",
+        "PROVENTOS", "DESCONTOS"
+    )
+    .unwrap();
+    writeln!(
+        saida,
+        "{}-+-{}",
+        "-".repeat(largura_esquerda),
+        "-".repeat(largura_direita)
+    )
+    .unwrap();
+
+    for linha in 0..proventos.len().max(descontos.len()) {
+        writeln!(
+            saida,
+            "{} | {}",
+            celula(proventos.get(linha), esquerda),
+            celula(descontos.get(linha), direita)
+        )
+        .unwrap();
+    }
+
+    writeln!(
+        saida,
+        "{}-+-{}",
+        "-".repeat(largura_esquerda),
+        "-".repeat(largura_direita)
+    )
+    .unwrap();
+    writeln!(
+        saida,
+        "{} | {}",
+        celula(Some(&total_esquerda), esquerda),
+        celula(Some(&total_direita), direita)
+    )
+    .unwrap();
+    writeln!(saida, "{}", "=".repeat(largura_total)).unwrap();
+
+    let liquido = format!("R$ {}", resultado.pagamento_final);
+    let largura_rotulo = largura_total - liquido.chars().count();
+    writeln!(saida, "{:<largura_rotulo$}{}", "LÍQUIDO", liquido).unwrap();
+
+    saida
+}
+
+/// Componentes do pagamento numa caixa com bordas Unicode, para
+/// terminais: uma linha por componente (deduções com sinal negativo) e o
+/// total separado no rodapé. As colunas se ajustam ao maior texto.
+pub fn resultado_tabela_unicode(resultado: &ResultadoCalculo) -> String {
+    let rotulo = |descricao: &str, padrao: &str| {
+        if descricao.is_empty() {
+            padrao.to_string()
+        } else {
+            descricao.to_string()
+        }
+    };
+
+    let mut linhas: Coluna = vec![
+        (
+            "Taxa fixa".to_string(),
+            format!("R$ {}", resultado.taxa_fixa),
+        ),
+        (
+            "Transporte".to_string(),
+            format!("R$ {}", resultado.custo_transporte),
+        ),
+    ];
+    linhas.extend(
+        resultado
+            .proventos
+            .iter()
+            .map(|p| (rotulo(&p.descricao, "Provento"), format!("R$ {}", p.valor))),
+    );
+    linhas.extend(
+        resultado
+            .deducoes
+            .iter()
+            .map(|d| (rotulo(&d.descricao, "Dedução"), format!("-R$ {}", d.valor))),
+    );
+    let total = (
+        "TOTAL".to_string(),
+        format!("R$ {}", resultado.pagamento_final),
+    );
+
+    let mut medida = linhas.clone();
+    medida.push(total.clone());
+    let (largura_rotulo, largura_valor) = largura_coluna(&medida);
+    let borda = |esquerda: &str, meio: &str, direita: &str| {
+        format!(
+            "{}{}{}{}{}",
+            esquerda,
+            "─".repeat(largura_rotulo + 2),
+            meio,
+            "─".repeat(largura_valor + 2),
+            direita
+        )
+    };
+    let linha =
+        |(r, v): &(String, String)| format!("│ {:<largura_rotulo$} │ {:>largura_valor$} │", r, v);
+
+    let mut saida = String::new();
+    writeln!(saida, "{}", borda("┌", "┬", "┐")).unwrap();
+    for item in &linhas {
+        writeln!(saida, "{}", linha(item)).unwrap();
+    }
+    writeln!(saida, "{}", borda("├", "┼", "┤")).unwrap();
+    writeln!(saida, "{}", linha(&total)).unwrap();
+    writeln!(saida, "{}", borda("└", "┴", "┘")).unwrap();
+
+    saida
+}
+
+// "+R$ 0.50", "-R$ 20.00"
+fn delta_reais(delta: Money) -> String {
+    if delta < Money::ZERO {
+        format!("-R$ {}", Money::ZERO - delta)
+    } else {
+        format!("+R$ {}", delta)
+    }
+}
+
+/// Só os campos que mudaram de `anterior` para `atual`, um por linha,
+/// como "Custo total do transporte: R$ 285.00 → R$ 315.00 (+R$ 30.00)".
+/// Sem nenhuma mudança, a saída é uma linha avisando isso.
+pub fn relatorio_diferencial(anterior: &ResultadoCalculo, atual: &ResultadoCalculo) -> String {
+    let mut saida = String::new();
+
+    let valores = [
+        ("Taxa fixa", anterior.taxa_fixa, atual.taxa_fixa),
+        (
+            "Taxa de transporte por viagem",
+            anterior.taxa_transporte,
+            atual.taxa_transporte,
+        ),
+        (
+            "Custo total do transporte",
+            anterior.custo_transporte,
+            atual.custo_transporte,
+        ),
+        (
+            "Total de proventos",
+            anterior.proventos_total,
+            atual.proventos_total,
+        ),
+        (
+            "Total de deduções",
+            anterior.deducoes_total,
+            atual.deducoes_total,
+        ),
+        (
+            "Pagamento final",
+            anterior.pagamento_final,
+            atual.pagamento_final,
+        ),
+    ];
+    let quantidades = [
+        (
+            "Dias trabalhados",
+            anterior.dias_trabalhados_fracionarios,
+            atual.dias_trabalhados_fracionarios,
+        ),
+        (
+            "Viagens por dia",
+            anterior.viagens_por_dia as f64,
+            atual.viagens_por_dia as f64,
+        ),
+    ];
+
+    for (rotulo, antes, depois) in quantidades {
+        if antes != depois {
+            writeln!(
+                saida,
+                "{}: {} → {} ({:+})",
+                rotulo,
+                antes,
+                depois,
+                depois - antes
+            )
+            .unwrap();
+        }
+    }
+    for (rotulo, antes, depois) in valores {
+        if antes != depois {
+            writeln!(
+                saida,
+                "{}: R$ {} → R$ {} ({})",
+                rotulo,
+                antes,
+                depois,
+                delta_reais(depois - antes)
+            )
+            .unwrap();
+        }
+    }
+
+    if saida.is_empty() {
+        saida.push_str("Nenhuma diferença\n");
+    }
+    saida
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calcular_valores, calcular_valores_com_calendario, Deducao};
+
+    #[test]
+    fn test_formatar_resultado_sem_cores() {
+        let resultado = calcular_valores_com_calendario(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            11,
+            2024,
+            2,
+            vec![Deducao {
+                valor: Money::from_reais(25),
+                descricao: "adiantamento".to_string(),
+            }],
+        )
+        .unwrap();
+
+        let texto = formatar_resultado(&resultado);
+
+        assert!(!texto.contains('\x1b'));
+        assert!(texto.contains("Mês/Ano: Novembro 2024\n"));
+        assert!(texto.contains("Feriados/dias não trabalhados: 2\n"));
+        assert!(texto.contains("  - R$ 25.00 (adiantamento)\n"));
+        assert!(texto.contains("PAGAMENTO FINAL: R$ 410.00\n"));
+    }
+
+    #[test]
+    fn test_formatar_resultado_lista_feriados() {
+        let resultado = crate::calcular_valores_com_feriados_nacionais(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            11,
+            2024,
+            vec![],
+        )
+        .unwrap();
+
+        let texto = formatar_resultado(&resultado);
+
+        assert!(texto.contains(
+            "Feriados/dias não trabalhados: 2\n\
+             \x20 02/11 Finados (fim de semana, não deduzido)\n\
+             \x20 15/11 Proclamação da República\n\
+             \x20 20/11 Consciência Negra\n\
+             Dias trabalhados: 19\n"
+        ));
+    }
+
+    #[test]
+    fn test_formatar_resultado_sem_lista_de_feriados() {
+        let resultado =
+            calcular_valores_com_calendario(Money::ZERO, Money::ZERO, 12, 2024, 1, vec![]).unwrap();
+
+        // Só a quantidade foi informada: nada a listar
+        assert!(formatar_resultado(&resultado)
+            .contains("Feriados/dias não trabalhados: 1\nDias trabalhados: 21\n"));
+    }
+
+    #[test]
+    fn test_formatar_resultado_sem_mes_ano() {
+        let resultado = calcular_dias_uteis_ja_contados();
+
+        let texto = formatar_resultado(&resultado);
+
+        assert!(!texto.contains("Mês/Ano"));
+        assert!(texto.contains("Dias úteis no mês: 20\n"));
+        assert!(texto.contains("Dias trabalhados: 20\n"));
+    }
+
+    fn calcular_dias_uteis_ja_contados() -> ResultadoCalculo {
+        crate::calcular_valores_com_dias_uteis(
+            Money::from_reais(100),
+            Money::from_reais(5),
+            20,
+            0,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_formatar_resultado_com_proventos() {
+        let mut resultado =
+            calcular_valores(Money::from_reais(100), Money::from_reais(5), 10, vec![]);
+        resultado.aplicar_bonus_assiduidade(Money::from_reais(50), 0);
+
+        let texto = formatar_resultado(&resultado);
+
+        assert!(texto.contains("Proventos:\n  + R$ 50.00 (Bônus de assiduidade)\n"));
+        assert!(texto.contains("Total de proventos: R$ 50.00\n"));
+        assert!(texto.contains("PAGAMENTO FINAL: R$ 250.00\n"));
+    }
+
+    #[test]
+    fn test_formatar_resultado_dias_fracionarios() {
+        let fracionario = crate::calcular_valores_fracionarios(
+            Money::from_reais(100),
+            Money::from_reais(5),
+            18.5,
+            vec![],
+        );
+        let inteiro = calcular_valores(Money::from_reais(100), Money::from_reais(5), 18, vec![]);
+
+        assert!(formatar_resultado(&fracionario).contains("  (18,5 dias × R$ 5.00 × 2 viagens)\n"));
+        assert!(formatar_resultado(&fracionario).contains("Custo total do transporte: R$ 185.00\n"));
+        assert!(formatar_resultado(&inteiro).contains("  (18 dias × R$ 5.00 × 2 viagens)\n"));
+        assert!(resumo_chat(&fracionario).ends_with("📅 Dias: 18,5"));
+        assert!(gerar_recibo(&fracionario, 40).contains("Transporte (18,5 dias)"));
+    }
+
+    #[test]
+    fn test_formatar_dias_com_casas_fixas() {
+        assert_eq!(formatar_dias(18.0, "dias", &TEXTOS_PT), "18 dias");
+        assert_eq!(formatar_dias(18.5, "dias", &TEXTOS_PT), "18,5 dias");
+        assert_eq!(formatar_dias(18.25, "dias", &TEXTOS_PT), "18,25 dias");
+        // Ruído de ponto flutuante não aparece no texto
+        assert_eq!(formatar_dias(0.1 + 0.2, "dias", &TEXTOS_PT), "0,3 dias");
+        assert_eq!(formatar_dias(0.0, "dias", &TEXTOS_PT), "0 dias");
+    }
+
+    #[test]
+    fn test_formatar_resultado_em_idioma() {
+        let resultado = crate::calcular_valores_com_calendario(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            11,
+            2024,
+            1,
+            vec![],
+        )
+        .unwrap();
+
+        let ingles = formatar_resultado_em_idioma(&resultado, Idioma::En, false);
+
+        assert!(ingles.contains("PAYMENT SUMMARY\n"));
+        assert!(ingles.contains("Month/Year: November 2024\n"));
+        assert!(ingles.contains("Days worked: 20\n"));
+        assert!(ingles.contains("  (20 days × R$ 7.50 × 2 trips)\n"));
+        assert!(ingles.contains("FINAL PAYMENT: R$ 450.00\n"));
+        assert_eq!(
+            formatar_resultado_em_idioma(&resultado, Idioma::Pt, false),
+            formatar_resultado(&resultado)
+        );
+    }
+
+    #[test]
+    fn test_formatar_resultado_meios_dias() {
+        let resultado = crate::calcular_valores_com_meios_dias(
+            Money::from_reais(1000),
+            Money::from_reais(5),
+            18,
+            3,
+            vec![],
+        );
+
+        let saida = formatar_resultado(&resultado);
+
+        assert!(saida.contains(
+            "  (18 dias inteiros × R$ 5.00 × 2 viagens + 3 meios-dias × R$ 5.00 × 1 viagem)\n"
+        ));
+        assert!(saida.contains("Custo total do transporte: R$ 195.00\n"));
+    }
+
+    #[test]
+    fn test_formatar_resultado_componentes_fixos() {
+        let resultado = crate::calcular_valores_com_componentes_fixos(
+            vec![
+                ("Salário base".to_string(), Money::from_reais(1412)),
+                ("Gratificação".to_string(), Money::parse("250.50").unwrap()),
+            ],
+            Money::from_reais(5),
+            10,
+            vec![],
+        );
+
+        let texto = formatar_resultado_brl(&resultado, false);
+
+        assert!(texto.contains(
+            "Taxa fixa: R$ 1.662,50\n  Salário base: R$ 1.412,00\n  Gratificação: R$ 250,50\n"
+        ));
+    }
+
+    #[test]
+    fn test_formatar_resultado_viagens_por_dia() {
+        let um_trajeto = crate::calcular_valores_com_viagens(
+            Money::from_reais(100),
+            Money::from_reais(5),
+            20,
+            1,
+            vec![],
+        );
+        let almoco_em_casa = crate::calcular_valores_com_viagens(
+            Money::from_reais(100),
+            Money::from_reais(5),
+            20,
+            4,
+            vec![],
+        );
+
+        assert!(formatar_resultado(&um_trajeto).contains("  (20 dias × R$ 5.00 × 1 viagem)\n"));
+        assert!(formatar_resultado(&almoco_em_casa).contains("  (20 dias × R$ 5.00 × 4 viagens)\n"));
+        assert!(
+            formatar_resultado(&almoco_em_casa).contains("Custo total do transporte: R$ 400.00\n")
+        );
+    }
+
+    #[test]
+    fn test_formatar_resultado_brl() {
+        let resultado = calcular_valores(
+            Money::parse("1500").unwrap(),
+            Money::parse("7.50").unwrap(),
+            20,
+            vec![Deducao {
+                valor: Money::parse("2000").unwrap(),
+                descricao: "adiantamento".to_string(),
+            }],
+        );
+
+        let texto = formatar_resultado_brl(&resultado, false);
+
+        assert!(texto.contains("Taxa fixa: R$ 1.500,00\n"));
+        assert!(texto.contains("  (20 dias × R$ 7,50 × 2 viagens)\n"));
+        assert!(texto.contains("  - R$ 2.000,00 (adiantamento)\n"));
+        assert!(texto.contains("PAGAMENTO FINAL: -R$ 200,00\n"));
+        assert!(formatar_resultado_brl(&resultado, true).contains("\x1b[31m-R$ 200,00\x1b[0m"));
+    }
+
+    #[test]
+    fn test_formatar_resultado_colorido_positivo() {
+        let resultado = calcular_valores(Money::from_reais(100), Money::from_reais(5), 10, vec![]);
+
+        let texto = formatar_resultado_colorido(&resultado);
+
+        assert!(texto.contains("\x1b[1mTaxa fixa:\x1b[0m R$ 100.00"));
+        assert!(texto.contains("\x1b[1mPAGAMENTO FINAL:\x1b[0m \x1b[1m\x1b[32mR$ 200.00\x1b[0m"));
+        assert!(!texto.contains(VERMELHO));
+    }
+
+    #[test]
+    fn test_formatar_resultado_colorido_negativo() {
+        let resultado = calcular_valores(
+            Money::from_reais(50),
+            Money::from_reais(5),
+            3,
+            vec![Deducao {
+                valor: Money::from_reais(100),
+                descricao: "grande dedução".to_string(),
+            }],
+        );
+
+        let texto = formatar_resultado_colorido(&resultado);
+
+        assert!(texto.contains("\x1b[1m\x1b[31mR$ -20.00\x1b[0m"));
+        assert!(!texto.contains(VERDE));
+    }
+
+    #[test]
+    fn test_versao_colorida_sem_escapes_igual_a_textual() {
+        let resultado = calcular_valores(Money::from_reais(100), Money::from_reais(5), 10, vec![]);
+
+        let colorido = formatar_resultado_colorido(&resultado);
+        let sem_escapes = [NEGRITO, VERDE, VERMELHO, RESET]
+            .iter()
+            .fold(colorido, |texto, codigo| texto.replace(codigo, ""));
+
+        assert_eq!(sem_escapes, formatar_resultado(&resultado));
+    }
+
+    #[test]
+    fn test_resumo_chat() {
+        let resultado = calcular_valores(
+            Money::from_reais(1000),
+            Money::parse("7.50").unwrap(),
+            21,
+            vec![],
+        );
+
+        let texto = resumo_chat(&resultado);
+
+        assert_eq!(
+            texto,
+            "💰 Pagamento: R$ 1.315,00\n🚌 Transporte: R$ 315,00\n📅 Dias: 21"
+        );
+    }
+
+    fn resultado_para_recibo() -> ResultadoCalculo {
+        calcular_valores_com_calendario(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            11,
+            2024,
+            2,
+            vec![
+                Deducao {
+                    valor: Money::from_reais(25),
+                    descricao: "adiantamento".to_string(),
+                },
+                Deducao {
+                    valor: Money::parse("1234.56").unwrap(),
+                    descricao: "parcela do empréstimo consignado com o banco".to_string(),
+                },
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_recibo_respeita_largura() {
+        let resultado = resultado_para_recibo();
+
+        for largura in [12, 20, 32, 40, 48] {
+            let recibo = gerar_recibo(&resultado, largura);
+            for linha in recibo.lines() {
+                assert!(
+                    linha.chars().count() <= largura,
+                    "largura {}: {:?}",
+                    largura,
+                    linha
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_recibo_40_colunas() {
+        let recibo = gerar_recibo(&resultado_para_recibo(), 40);
+        let linhas: Vec<&str> = recibo.lines().collect();
+
+        assert_eq!(linhas[1], "          RECIBO DE PAGAMENTO           ");
+        assert_eq!(linhas[2].trim(), "Novembro 2024");
+        assert!(linhas.contains(&"Taxa fixa                      R$ 150.00"));
+        // Rótulo longo quebrado, valor alinhado à direita na última linha
+        assert!(linhas.contains(&"parcela do empréstimo consignado com o"));
+        assert!(linhas.contains(&"banco                        -R$ 1234.56"));
+        assert!(linhas.contains(&"TOTAL                         R$ -824.56"));
+    }
+
+    #[test]
+    fn test_duas_colunas_alinhamento() {
+        let mut resultado = calcular_valores(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            19,
+            vec![
+                Deducao {
+                    valor: Money::from_reais(25),
+                    descricao: "adiantamento".to_string(),
+                },
+                Deducao {
+                    valor: Money::parse("1234.56").unwrap(),
+                    descricao: "empréstimo".to_string(),
+                },
+                Deducao {
+                    valor: Money::from_reais(10),
+                    descricao: "faltas".to_string(),
+                },
+            ],
+        );
+        resultado.aplicar_bonus_assiduidade(Money::from_reais(50), 0);
+
+        let texto = formatar_duas_colunas_linhas(&resultado);
+        let largura = texto[0].chars().count();
+
+        // Todas as linhas têm a mesma largura e o separador na mesma coluna
+        assert!(texto.iter().all(|l| l.chars().count() == largura));
+        let posicao_separador = texto[0].chars().position(|c| c == '|').unwrap();
+        for linha in &texto[..texto.len() - 2] {
+            let c = linha.chars().nth(posicao_separador).unwrap();
+            assert!(c == '|' || c == '+', "linha desalinhada: {:?}", linha);
+        }
+    }
+
+    #[test]
+    fn test_duas_colunas_totais() {
+        let mut resultado = calcular_valores(
+            Money::from_reais(150),
+            Money::parse("7.50").unwrap(),
+            19,
+            vec![Deducao {
+                valor: Money::from_reais(25),
+                descricao: "adiantamento".to_string(),
+            }],
+        );
+        resultado.aplicar_bonus_assiduidade(Money::from_reais(50), 0);
+
+        let texto = resultado_duas_colunas(&resultado);
+
+        // 150 + 285 + 50 = 485; líquido = 485 - 25 = 460
+        let totais = texto
+            .lines()
+            .find(|l| l.starts_with("Total proventos"))
+            .unwrap();
+        let (proventos, descontos) = totais.split_once(" | ").unwrap();
+        assert!(proventos.ends_with("R$ 485.00"));
+        assert!(descontos.starts_with("Total descontos"));
+        assert!(descontos.ends_with("R$ 25.00"));
+        assert!(texto.contains("Bônus de assiduidade"));
+        assert!(texto.lines().last().unwrap().starts_with("LÍQUIDO"));
+        assert!(texto.lines().last().unwrap().ends_with("R$ 460.00"));
+    }
+
+    #[test]
+    fn test_duas_colunas_sem_descontos() {
+        let resultado = calcular_valores(Money::from_reais(100), Money::from_reais(5), 10, vec![]);
+
+        let texto = resultado_duas_colunas(&resultado);
+
+        assert!(texto.contains("Total descontos R$ 0.00"));
+        assert!(!texto.contains("Dedução"));
+        assert!(texto.lines().last().unwrap().ends_with("R$ 200.00"));
+    }
+
+    fn formatar_duas_colunas_linhas(resultado: &ResultadoCalculo) -> Vec<String> {
+        resultado_duas_colunas(resultado)
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    fn mes_com(taxa_transporte: &str, dias: i32, deducao: &str) -> ResultadoCalculo {
+        calcular_valores(
+            Money::from_reais(1500),
+            Money::parse(taxa_transporte).unwrap(),
+            dias,
+            vec![Deducao {
+                valor: Money::parse(deducao).unwrap(),
+                descricao: String::new(),
+            }],
+        )
+    }
+
+    #[test]
+    fn test_relatorio_diferencial() {
+        let outubro = mes_com("7.50", 19, "50");
+        let novembro = mes_com("7.50", 21, "30");
+
+        let texto = relatorio_diferencial(&outubro, &novembro);
+
+        assert_eq!(
+            texto,
+            "Dias trabalhados: 19 → 21 (+2)\n\
+             Custo total do transporte: R$ 285.00 → R$ 315.00 (+R$ 30.00)\n\
+             Total de deduções: R$ 50.00 → R$ 30.00 (-R$ 20.00)\n\
+             Pagamento final: R$ 1735.00 → R$ 1785.00 (+R$ 50.00)\n"
+        );
+        assert!(!texto.contains("Taxa fixa"));
+        assert!(!texto.contains("Viagens por dia"));
+    }
+
+    #[test]
+    fn test_relatorio_diferencial_tarifa_e_sem_mudancas() {
+        let antes = mes_com("7.50", 20, "0");
+        let depois = mes_com("8.00", 20, "0");
+
+        let texto = relatorio_diferencial(&antes, &depois);
+
+        assert!(texto.starts_with("Taxa de transporte por viagem: R$ 7.50 → R$ 8.00 (+R$ 0.50)\n"));
+        assert_eq!(relatorio_diferencial(&antes, &antes), "Nenhuma diferença\n");
+    }
+
+    #[test]
+    fn test_resultado_tabela_unicode_retangular() {
+        let mut resultado = calcular_valores(
+            Money::from_reais(1500),
+            Money::from_reais(5),
+            20,
+            vec![
+                Deducao {
+                    valor: Money::from_reais(100),
+                    descricao: "Adiantamento salarial".to_string(),
+                },
+                Deducao {
+                    valor: Money::parse("12.34").unwrap(),
+                    descricao: String::new(),
+                },
+            ],
+        );
+        resultado.adicionar_provento(crate::Provento {
+            valor: Money::from_reais(50),
+            descricao: "Bônus".to_string(),
+        });
+
+        let tabela = resultado_tabela_unicode(&resultado);
+        let linhas: Vec<&str> = tabela.lines().collect();
+
+        let largura = linhas[0].chars().count();
+        assert!(linhas.iter().all(|l| l.chars().count() == largura));
+        assert!(linhas[0].starts_with('┌') && linhas[0].ends_with('┐'));
+        assert!(linhas.last().unwrap().starts_with('└'));
+        assert_eq!(linhas[1], "│ Taxa fixa             │ R$ 1500.00 │");
+        assert_eq!(linhas[5], "│ Dedução               │  -R$ 12.34 │");
+        assert!(linhas[6].starts_with('├'));
+        assert_eq!(linhas[7], "│ TOTAL                 │ R$ 1637.66 │");
+    }
+}
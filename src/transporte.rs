@@ -0,0 +1,790 @@
+use crate::analise::dividir_arredondado;
+use crate::{
+    calcular_transporte_simples, contar_dias_uteis, listar_dias_uteis, ErroCalculo, Money,
+    JORNADA_PADRAO,
+};
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CategoriaDia {
+    Integral,
+    MeioPeriodo,
+    Folga,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParametrosDia {
+    pub viagens: i32,
+    pub tarifa: Money, // por viagem
+}
+
+// Folga não tem parâmetros: nunca gera viagens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TarifasPorCategoria {
+    pub integral: ParametrosDia,
+    pub meio_periodo: ParametrosDia,
+}
+
+impl TarifasPorCategoria {
+    pub fn parametros(&self, categoria: CategoriaDia) -> ParametrosDia {
+        match categoria {
+            CategoriaDia::Integral => self.integral,
+            CategoriaDia::MeioPeriodo => self.meio_periodo,
+            CategoriaDia::Folga => ParametrosDia {
+                viagens: 0,
+                tarifa: Money::ZERO,
+            },
+        }
+    }
+}
+
+pub fn custo_transporte_por_categorias(
+    dias: &[CategoriaDia],
+    tarifas: &TarifasPorCategoria,
+) -> Money {
+    dias.iter().fold(Money::ZERO, |acc, &categoria| {
+        let parametros = tarifas.parametros(categoria);
+        acc + parametros.tarifa * parametros.viagens
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransporteComSextas {
+    pub sextas_meio_periodo: i32,
+    pub custo_transporte: Money,
+}
+
+/// Custo dos dias úteis do mês como integrais; com `meio_periodo_sexta`,
+/// as sextas úteis contam como meio período sem precisar listá-las.
+pub fn calcular_transporte_com_sextas(
+    mes: u32,
+    ano: i32,
+    tarifas: &TarifasPorCategoria,
+    meio_periodo_sexta: bool,
+) -> Result<TransporteComSextas, ErroCalculo> {
+    let dias: Vec<CategoriaDia> = listar_dias_uteis(mes, ano)?
+        .iter()
+        .map(|data| match data.weekday() {
+            Weekday::Fri if meio_periodo_sexta => CategoriaDia::MeioPeriodo,
+            _ => CategoriaDia::Integral,
+        })
+        .collect();
+
+    Ok(TransporteComSextas {
+        sextas_meio_periodo: dias
+            .iter()
+            .filter(|&&d| d == CategoriaDia::MeioPeriodo)
+            .count() as i32,
+        custo_transporte: custo_transporte_por_categorias(&dias, tarifas),
+    })
+}
+
+// Bilhete único: embarques dentro da janela contada a partir do embarque
+// pago pagam só um percentual da tarifa (0 = grátis, 50 = metade)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegraIntegracao {
+    pub janela_minutos: i64,
+    pub percentual_integracao: u32,
+    pub max_integracoes: u32, // por embarque pago
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CustoIntegrado {
+    pub embarques: i32,
+    pub embarques_integrados: i32,
+    pub custo_diario: Money,
+    pub custo_sem_integracao: Money,
+}
+
+/// Custo do dia considerando a integração. Os embarques são ordenados por
+/// horário; cada embarque com tarifa cheia abre uma janela durante a qual
+/// até `max_integracoes` embarques seguintes pagam o valor integrado.
+pub fn custo_diario_integrado(
+    tarifa: Money,
+    embarques: &[NaiveTime],
+    regra: &RegraIntegracao,
+) -> CustoIntegrado {
+    let tarifa_integrada = Money::from_centavos(dividir_arredondado(
+        tarifa.to_centavos() * regra.percentual_integracao as i64,
+        100,
+    ));
+
+    let mut horarios = embarques.to_vec();
+    horarios.sort();
+
+    let mut custo_diario = Money::ZERO;
+    let mut embarques_integrados = 0;
+    let mut janela: Option<(NaiveTime, u32)> = None; // embarque pago, integrações usadas
+
+    for horario in horarios {
+        match janela {
+            Some((pago, usadas))
+                if usadas < regra.max_integracoes
+                    && (horario - pago).num_minutes() <= regra.janela_minutos =>
+            {
+                custo_diario = custo_diario + tarifa_integrada;
+                embarques_integrados += 1;
+                janela = Some((pago, usadas + 1));
+            }
+            _ => {
+                custo_diario = custo_diario + tarifa;
+                janela = Some((horario, 0));
+            }
+        }
+    }
+
+    CustoIntegrado {
+        embarques: embarques.len() as i32,
+        embarques_integrados,
+        custo_diario,
+        custo_sem_integracao: tarifa * embarques.len() as i32,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConciliacaoVale {
+    pub custo_transporte: Money,
+    pub vale_creditado: Money,
+    pub custo_proprio: Money,  // o que sai do bolso do trabalhador
+    pub vale_excedente: Money, // vale que sobrou no mês
+}
+
+pub fn conciliar_vale(custo_transporte: Money, vale_creditado: Money) -> ConciliacaoVale {
+    ConciliacaoVale {
+        custo_transporte,
+        vale_creditado,
+        custo_proprio: (custo_transporte - vale_creditado).max(Money::ZERO),
+        vale_excedente: (vale_creditado - custo_transporte).max(Money::ZERO),
+    }
+}
+
+/// Diferença no custo mensal de transporte ao passar da tarifa antiga
+/// para a nova; negativa quando a tarifa cai.
+pub fn impacto_aumento_tarifa(
+    tarifa_antiga: Money,
+    tarifa_nova: Money,
+    dias: i32,
+    viagens: i32,
+) -> Money {
+    calcular_transporte_simples(tarifa_nova, dias, viagens)
+        - calcular_transporte_simples(tarifa_antiga, dias, viagens)
+}
+
+/// Custo mensal de ir de ônibus, de ir de aplicativo e quanto se
+/// economiza escolhendo o mais barato, nessa ordem.
+pub fn comparar_meios(
+    onibus: Money,
+    app_por_viagem: Money,
+    dias: i32,
+    viagens: i32,
+) -> (Money, Money, Money) {
+    let custo_onibus = calcular_transporte_simples(onibus, dias, viagens);
+    let custo_app = calcular_transporte_simples(app_por_viagem, dias, viagens);
+    let economia = custo_onibus.max(custo_app) - custo_onibus.min(custo_app);
+
+    (custo_onibus, custo_app, economia)
+}
+
+/// Transporte evitado no mês pelos dias de trabalho remoto. Os dias
+/// remotos são limitados aos dias úteis do mês; negativos contam como zero.
+pub fn economia_home_office(
+    taxa_transporte: Money,
+    dias_remotos: i32,
+    mes: u32,
+    ano: i32,
+    viagens: i32,
+) -> Result<Money, ErroCalculo> {
+    let dias_uteis = contar_dias_uteis(mes, ano)?;
+
+    Ok(calcular_transporte_simples(
+        taxa_transporte,
+        dias_remotos.clamp(0, dias_uteis),
+        viagens,
+    ))
+}
+
+/// Dias inteiros de transporte descontados pelos atrasos acumulados no
+/// mês: cada `minutos_por_dia` de atraso tira um dia, arredondando para
+/// baixo. Atraso negativo ou jornada não positiva não descontam nada.
+pub fn descontar_atrasos_em_dias(minutos_atraso_total: i64, minutos_por_dia: i64) -> i32 {
+    if minutos_atraso_total <= 0 || minutos_por_dia <= 0 {
+        return 0;
+    }
+
+    (minutos_atraso_total / minutos_por_dia).min(i32::MAX as i64) as i32
+}
+
+/// Soma, de janeiro a dezembro, o transporte evitado pelos dias remotos
+/// de cada mês (`dias_remotos_por_mes[0]` é janeiro).
+pub fn economia_anual_hibrido(
+    taxa_transporte: Money,
+    dias_remotos_por_mes: &[i32; 12],
+    ano: i32,
+    viagens: i32,
+) -> Result<Money, ErroCalculo> {
+    (1..=12)
+        .zip(dias_remotos_por_mes)
+        .map(|(mes, &dias_remotos)| {
+            economia_home_office(taxa_transporte, dias_remotos, mes, ano, viagens)
+        })
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SemanaFiscal {
+    pub inicio: NaiveDate, // pode cair no mês anterior
+    pub dias_uteis: i32,
+    pub custo_transporte: Money,
+}
+
+/// Distribui os dias úteis do mês (e o custo das `viagens_por_dia` de
+/// cada um) entre semanas fiscais que começam em `inicio_semana_fiscal`.
+/// Os `feriados` que caem em dia útil ficam de fora, então a soma das
+/// semanas é o custo do mês com os mesmos feriados deduzidos. Só entram
+/// semanas com pelo menos um dia útil dentro do mês.
+pub fn agrupar_por_semana_fiscal(
+    mes: u32,
+    ano: i32,
+    taxa_transporte: Money,
+    viagens_por_dia: i32,
+    inicio_semana_fiscal: Weekday,
+    feriados: &[NaiveDate],
+) -> Result<Vec<SemanaFiscal>, ErroCalculo> {
+    let custo_dia = taxa_transporte * viagens_por_dia;
+    let mut semanas: Vec<SemanaFiscal> = Vec::new();
+
+    let dias_uteis = listar_dias_uteis(mes, ano)?;
+    for data in dias_uteis
+        .into_iter()
+        .filter(|data| !feriados.contains(data))
+    {
+        let deslocamento = (data.weekday().num_days_from_monday() + 7
+            - inicio_semana_fiscal.num_days_from_monday())
+            % 7;
+        let inicio = data - chrono::Duration::days(deslocamento as i64);
+
+        match semanas.last_mut() {
+            Some(semana) if semana.inicio == inicio => {
+                semana.dias_uteis += 1;
+                semana.custo_transporte = semana.custo_transporte + custo_dia;
+            }
+            _ => semanas.push(SemanaFiscal {
+                inicio,
+                dias_uteis: 1,
+                custo_transporte: custo_dia,
+            }),
+        }
+    }
+
+    Ok(semanas)
+}
+
+// Dias da semana sem entrada no mapa usam `viagens_padrao`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerfilViagens {
+    pub viagens_por_dia: HashMap<Weekday, i32>,
+    pub viagens_padrao: i32,
+}
+
+impl PerfilViagens {
+    pub fn viagens(&self, dia_semana: Weekday) -> i32 {
+        self.viagens_por_dia
+            .get(&dia_semana)
+            .copied()
+            .unwrap_or(self.viagens_padrao)
+    }
+
+    // Total de viagens de uma semana de segunda a sexta
+    pub fn viagens_semana_tipo(&self) -> i32 {
+        JORNADA_PADRAO.iter().map(|&d| self.viagens(d)).sum()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransportePorPerfil {
+    pub viagens_semana_tipo: i32,
+    pub viagens_mes: i32,
+    pub custo_transporte: Money,
+}
+
+pub fn calcular_transporte_por_perfil(
+    mes: u32,
+    ano: i32,
+    taxa_transporte: Money,
+    perfil: &PerfilViagens,
+) -> Result<TransportePorPerfil, ErroCalculo> {
+    let viagens_mes = listar_dias_uteis(mes, ano)?
+        .iter()
+        .map(|data| perfil.viagens(data.weekday()))
+        .sum();
+
+    Ok(TransportePorPerfil {
+        viagens_semana_tipo: perfil.viagens_semana_tipo(),
+        viagens_mes,
+        custo_transporte: taxa_transporte * viagens_mes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calcular_valores, calcular_valores_com_calendario};
+
+    fn data(ano: i32, mes: u32, dia: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(ano, mes, dia).unwrap()
+    }
+
+    fn tarifas() -> TarifasPorCategoria {
+        TarifasPorCategoria {
+            integral: ParametrosDia {
+                viagens: 2,
+                tarifa: Money::parse("5.00").unwrap(),
+            },
+            // tarifa social: metade da tarifa cheia
+            meio_periodo: ParametrosDia {
+                viagens: 2,
+                tarifa: Money::parse("2.50").unwrap(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_dias_integrais_batem_com_calculo_padrao() {
+        let dias = [CategoriaDia::Integral; 10];
+
+        let custo = custo_transporte_por_categorias(&dias, &tarifas());
+        let padrao = calcular_valores(Money::ZERO, Money::from_reais(5), 10, vec![]);
+
+        assert_eq!(custo, padrao.custo_transporte);
+    }
+
+    #[test]
+    fn test_mistura_integral_e_meio_periodo() {
+        let mut dias = vec![CategoriaDia::Integral; 15];
+        dias.extend([CategoriaDia::MeioPeriodo; 4]);
+        dias.extend([CategoriaDia::Folga; 2]);
+
+        let custo = custo_transporte_por_categorias(&dias, &tarifas());
+
+        // 15 × 5.00 × 2 + 4 × 2.50 × 2 = 150.00 + 20.00
+        assert_eq!(custo, Money::from_reais(170));
+    }
+
+    #[test]
+    fn test_meio_periodo_com_uma_viagem() {
+        let tarifas = TarifasPorCategoria {
+            meio_periodo: ParametrosDia {
+                viagens: 1,
+                tarifa: Money::parse("2.50").unwrap(),
+            },
+            ..tarifas()
+        };
+        let dias = [
+            CategoriaDia::Integral,
+            CategoriaDia::MeioPeriodo,
+            CategoriaDia::MeioPeriodo,
+        ];
+
+        // 1 × 5.00 × 2 + 2 × 2.50 × 1 = 10.00 + 5.00
+        assert_eq!(
+            custo_transporte_por_categorias(&dias, &tarifas),
+            Money::from_reais(15)
+        );
+    }
+
+    #[test]
+    fn test_folgas_nao_geram_custo() {
+        let dias = [CategoriaDia::Folga; 5];
+
+        assert_eq!(
+            custo_transporte_por_categorias(&dias, &tarifas()),
+            Money::ZERO
+        );
+        assert_eq!(
+            custo_transporte_por_categorias(&[], &tarifas()),
+            Money::ZERO
+        );
+    }
+
+    #[test]
+    fn test_meio_periodo_sexta_ligado() {
+        // Novembro de 2024: 21 dias úteis, 5 deles sextas
+        let resultado = calcular_transporte_com_sextas(11, 2024, &tarifas(), true).unwrap();
+
+        assert_eq!(resultado.sextas_meio_periodo, 5);
+        // 16 × 5.00 × 2 + 5 × 2.50 × 2 = 160.00 + 25.00
+        assert_eq!(resultado.custo_transporte, Money::from_reais(185));
+    }
+
+    #[test]
+    fn test_meio_periodo_sexta_desligado() {
+        let resultado = calcular_transporte_com_sextas(11, 2024, &tarifas(), false).unwrap();
+        let padrao = calcular_valores(Money::ZERO, Money::from_reais(5), 21, vec![]);
+
+        assert_eq!(resultado.sextas_meio_periodo, 0);
+        assert_eq!(resultado.custo_transporte, padrao.custo_transporte);
+        assert_eq!(
+            calcular_transporte_com_sextas(13, 2024, &tarifas(), true),
+            Err(ErroCalculo::MesInvalido(13))
+        );
+    }
+
+    fn hora(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    fn meia_tarifa_em_duas_horas() -> RegraIntegracao {
+        RegraIntegracao {
+            janela_minutos: 120,
+            percentual_integracao: 50,
+            max_integracoes: 1,
+        }
+    }
+
+    #[test]
+    fn test_integracao_aplicavel() {
+        // Ida com duas conduções, volta com duas conduções
+        let embarques = [hora(7, 0), hora(7, 40), hora(18, 0), hora(19, 59)];
+
+        let custo = custo_diario_integrado(
+            Money::parse("4.40").unwrap(),
+            &embarques,
+            &meia_tarifa_em_duas_horas(),
+        );
+
+        assert_eq!(custo.embarques, 4);
+        assert_eq!(custo.embarques_integrados, 2);
+        // 2 × 4.40 + 2 × 2.20
+        assert_eq!(custo.custo_diario, Money::parse("13.20").unwrap());
+        assert_eq!(custo.custo_sem_integracao, Money::parse("17.60").unwrap());
+    }
+
+    #[test]
+    fn test_integracao_fora_da_janela() {
+        let embarques = [hora(7, 0), hora(9, 1), hora(18, 0)];
+
+        let custo = custo_diario_integrado(
+            Money::from_reais(5),
+            &embarques,
+            &meia_tarifa_em_duas_horas(),
+        );
+
+        assert_eq!(custo.embarques_integrados, 0);
+        assert_eq!(custo.custo_diario, custo.custo_sem_integracao);
+    }
+
+    #[test]
+    fn test_integracao_limite_por_embarque_pago() {
+        let gratis_ate_duas = RegraIntegracao {
+            janela_minutos: 180,
+            percentual_integracao: 0,
+            max_integracoes: 2,
+        };
+        // Fora de ordem: a ordenação é por horário
+        let embarques = [hora(8, 30), hora(8, 0), hora(9, 0), hora(8, 15)];
+
+        let custo = custo_diario_integrado(Money::from_reais(5), &embarques, &gratis_ate_duas);
+
+        // 8:00 paga, 8:15 e 8:30 grátis, 9:00 paga de novo
+        assert_eq!(custo.embarques_integrados, 2);
+        assert_eq!(custo.custo_diario, Money::from_reais(10));
+    }
+
+    #[test]
+    fn test_conciliar_vale_insuficiente() {
+        let conciliacao = conciliar_vale(Money::from_reais(285), Money::from_reais(200));
+
+        assert_eq!(conciliacao.custo_proprio, Money::from_reais(85));
+        assert_eq!(conciliacao.vale_excedente, Money::ZERO);
+    }
+
+    #[test]
+    fn test_conciliar_vale_excedente() {
+        let conciliacao = conciliar_vale(Money::from_reais(150), Money::parse("180.50").unwrap());
+
+        assert_eq!(conciliacao.custo_proprio, Money::ZERO);
+        assert_eq!(conciliacao.vale_excedente, Money::parse("30.50").unwrap());
+    }
+
+    #[test]
+    fn test_conciliar_vale_exato() {
+        let conciliacao = conciliar_vale(Money::from_reais(150), Money::from_reais(150));
+
+        assert_eq!(conciliacao.custo_proprio, Money::ZERO);
+        assert_eq!(conciliacao.vale_excedente, Money::ZERO);
+    }
+
+    fn dias_por_semana(semanas: &[SemanaFiscal]) -> Vec<i32> {
+        semanas.iter().map(|s| s.dias_uteis).collect()
+    }
+
+    #[test]
+    fn test_impacto_aumento_tarifa() {
+        // (5.00 - 4.40) × 21 dias × 2 viagens
+        assert_eq!(
+            impacto_aumento_tarifa(
+                Money::parse("4.40").unwrap(),
+                Money::parse("5.00").unwrap(),
+                21,
+                2
+            ),
+            Money::parse("25.20").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_impacto_reducao_tarifa() {
+        assert_eq!(
+            impacto_aumento_tarifa(
+                Money::parse("5.00").unwrap(),
+                Money::parse("4.50").unwrap(),
+                20,
+                4
+            ),
+            Money::parse("-40.00").unwrap()
+        );
+        assert_eq!(
+            impacto_aumento_tarifa(Money::from_reais(5), Money::from_reais(5), 20, 2),
+            Money::ZERO
+        );
+    }
+
+    #[test]
+    fn test_comparar_meios_onibus_mais_barato() {
+        let (onibus, app, economia) = comparar_meios(
+            Money::parse("4.40").unwrap(),
+            Money::parse("18.90").unwrap(),
+            20,
+            2,
+        );
+
+        assert_eq!(onibus, Money::from_reais(176));
+        assert_eq!(app, Money::from_reais(756));
+        assert_eq!(economia, Money::from_reais(580));
+    }
+
+    #[test]
+    fn test_comparar_meios_app_mais_barato() {
+        // Ônibus com duas conduções por trecho, app compartilhado
+        let (onibus, app, economia) =
+            comparar_meios(Money::from_reais(9), Money::parse("8.50").unwrap(), 10, 2);
+
+        assert_eq!(onibus, Money::from_reais(180));
+        assert_eq!(app, Money::from_reais(170));
+        assert_eq!(economia, Money::from_reais(10));
+        assert_eq!(
+            comparar_meios(Money::from_reais(5), Money::from_reais(5), 10, 2).2,
+            Money::ZERO
+        );
+    }
+
+    #[test]
+    fn test_descontar_atrasos_em_dias() {
+        let jornada = 8 * 60;
+
+        // 7h de atraso não completam um dia
+        assert_eq!(descontar_atrasos_em_dias(420, jornada), 0);
+        assert_eq!(descontar_atrasos_em_dias(480, jornada), 1);
+        // 17h50 completam dois dias; o resto não conta
+        assert_eq!(descontar_atrasos_em_dias(17 * 60 + 50, jornada), 2);
+        assert_eq!(descontar_atrasos_em_dias(-30, jornada), 0);
+        assert_eq!(descontar_atrasos_em_dias(600, 0), 0);
+    }
+
+    #[test]
+    fn test_atrasos_reduzem_dias_de_transporte() {
+        let desconto = descontar_atrasos_em_dias(10 * 60, 8 * 60);
+
+        let resultado = calcular_valores(
+            Money::from_reais(1000),
+            Money::from_reais(5),
+            20 - desconto,
+            vec![],
+        );
+
+        assert_eq!(resultado.custo_transporte, Money::from_reais(190));
+    }
+
+    #[test]
+    fn test_economia_home_office_limitada_aos_dias_uteis() {
+        let tarifa = Money::parse("4.40").unwrap();
+
+        // 8 dias × 4.40 × 2
+        assert_eq!(
+            economia_home_office(tarifa, 8, 11, 2024, 2),
+            Ok(Money::parse("70.40").unwrap())
+        );
+        // Novembro de 2024 tem 21 dias úteis
+        assert_eq!(
+            economia_home_office(tarifa, 30, 11, 2024, 2),
+            economia_home_office(tarifa, 21, 11, 2024, 2)
+        );
+        assert_eq!(
+            economia_home_office(tarifa, -3, 11, 2024, 2),
+            Ok(Money::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_economia_anual_hibrido() {
+        // Dois dias remotos por semana, menos em janeiro (férias) e dezembro
+        let dias_remotos = [0, 8, 8, 8, 9, 8, 9, 9, 8, 9, 8, 4];
+
+        let economia =
+            economia_anual_hibrido(Money::parse("4.40").unwrap(), &dias_remotos, 2024, 2).unwrap();
+
+        // 88 dias × 4.40 × 2
+        assert_eq!(economia, Money::parse("774.40").unwrap());
+        assert_eq!(
+            economia_anual_hibrido(Money::parse("4.40").unwrap(), &[0; 12], 2024, 2),
+            Ok(Money::ZERO)
+        );
+        assert_eq!(
+            economia_anual_hibrido(Money::parse("4.40").unwrap(), &dias_remotos, 2101, 2),
+            Err(ErroCalculo::AnoInvalido(2101))
+        );
+    }
+
+    #[test]
+    fn test_semana_fiscal_iniciando_na_segunda() {
+        let semanas =
+            agrupar_por_semana_fiscal(11, 2024, Money::from_reais(5), 2, Weekday::Mon, &[])
+                .unwrap();
+
+        // Novembro 2024 começa numa sexta: a primeira semana é a de 28/10
+        assert_eq!(dias_por_semana(&semanas), vec![1, 5, 5, 5, 5]);
+        assert_eq!(
+            semanas[0].inicio,
+            NaiveDate::from_ymd_opt(2024, 10, 28).unwrap()
+        );
+        assert_eq!(semanas[0].custo_transporte, Money::from_reais(10));
+    }
+
+    #[test]
+    fn test_semana_fiscal_fechando_na_quinta() {
+        let semanas =
+            agrupar_por_semana_fiscal(11, 2024, Money::from_reais(5), 2, Weekday::Fri, &[])
+                .unwrap();
+
+        assert_eq!(dias_por_semana(&semanas), vec![5, 5, 5, 5, 1]);
+        assert!(semanas.iter().all(|s| s.inicio.weekday() == Weekday::Fri));
+
+        let semanas =
+            agrupar_por_semana_fiscal(11, 2024, Money::from_reais(5), 2, Weekday::Thu, &[])
+                .unwrap();
+
+        assert_eq!(dias_por_semana(&semanas), vec![4, 5, 5, 5, 2]);
+    }
+
+    #[test]
+    fn test_soma_semanas_fiscais_igual_total_mensal() {
+        let taxa = Money::parse("7.50").unwrap();
+        // Fevereiro de 2024: Carnaval na segunda (12) e na terça (13);
+        // o sábado 10 não é dia útil e não muda nada
+        let feriados = [data(2024, 2, 10), data(2024, 2, 12), data(2024, 2, 13)];
+        let mensal =
+            calcular_valores_com_calendario(Money::ZERO, taxa, 2, 2024, 2, vec![]).unwrap();
+
+        for inicio in [Weekday::Mon, Weekday::Wed, Weekday::Thu, Weekday::Sun] {
+            let semanas = agrupar_por_semana_fiscal(2, 2024, taxa, 2, inicio, &feriados).unwrap();
+            let total = semanas
+                .iter()
+                .fold(Money::ZERO, |acc, s| acc + s.custo_transporte);
+
+            assert_eq!(total, mensal.custo_transporte);
+            assert_eq!(semanas.iter().map(|s| s.dias_uteis).sum::<i32>(), 19);
+        }
+    }
+
+    #[test]
+    fn test_semana_fiscal_com_viagens_por_dia() {
+        // Três conduções por dia: 10/11/2024 a 14/11 são 4 dias úteis
+        // na semana de domingo, 15/11 é feriado
+        let semanas = agrupar_por_semana_fiscal(
+            11,
+            2024,
+            Money::from_reais(5),
+            3,
+            Weekday::Sun,
+            &[data(2024, 11, 15)],
+        )
+        .unwrap();
+
+        assert_eq!(dias_por_semana(&semanas), vec![1, 5, 4, 5, 5]);
+        assert_eq!(semanas[2].inicio, data(2024, 11, 10));
+        assert_eq!(semanas[2].custo_transporte, Money::from_reais(60));
+    }
+
+    #[test]
+    fn test_semana_fiscal_mes_invalido() {
+        assert!(
+            agrupar_por_semana_fiscal(13, 2024, Money::from_reais(5), 2, Weekday::Mon, &[])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_perfil_com_sexta_de_uma_viagem() {
+        let perfil = PerfilViagens {
+            viagens_por_dia: HashMap::from([(Weekday::Fri, 1)]),
+            viagens_padrao: 2,
+        };
+
+        let transporte =
+            calcular_transporte_por_perfil(11, 2024, Money::from_reais(5), &perfil).unwrap();
+
+        // Novembro 2024: 21 dias úteis, 5 deles sextas
+        assert_eq!(transporte.viagens_semana_tipo, 9); // 4 × 2 + 1
+        assert_eq!(transporte.viagens_mes, 16 * 2 + 5);
+        assert_eq!(transporte.custo_transporte, Money::from_reais(185)); // 37 × 5
+    }
+
+    #[test]
+    fn test_perfil_variando_por_dia() {
+        let perfil = PerfilViagens {
+            viagens_por_dia: HashMap::from([
+                (Weekday::Mon, 2),
+                (Weekday::Wed, 4), // almoça em casa
+                (Weekday::Fri, 1),
+            ]),
+            viagens_padrao: 0, // terça e quinta remotas
+        };
+
+        let transporte =
+            calcular_transporte_por_perfil(2, 2024, Money::from_reais(5), &perfil).unwrap();
+
+        // Fevereiro 2024: 4 segundas, 4 quartas, 4 sextas
+        assert_eq!(transporte.viagens_semana_tipo, 7);
+        assert_eq!(transporte.viagens_mes, 4 * 2 + 4 * 4 + 4);
+        assert_eq!(transporte.custo_transporte, Money::from_reais(140));
+    }
+
+    #[test]
+    fn test_perfil_padrao_bate_com_calculo_padrao() {
+        let perfil = PerfilViagens {
+            viagens_por_dia: HashMap::new(),
+            viagens_padrao: 2,
+        };
+        let taxa = Money::parse("7.50").unwrap();
+
+        let transporte = calcular_transporte_por_perfil(11, 2024, taxa, &perfil).unwrap();
+        let calculo = calcular_valores(Money::ZERO, taxa, 21, vec![]);
+
+        assert_eq!(transporte.custo_transporte, calculo.custo_transporte);
+    }
+
+    #[test]
+    fn test_perfil_sabado_ignorado() {
+        let perfil = PerfilViagens {
+            viagens_por_dia: HashMap::from([(Weekday::Sat, 2)]),
+            viagens_padrao: 2,
+        };
+
+        // Sábado não é dia útil, então não aparece na semana-tipo nem no mês
+        assert_eq!(perfil.viagens_semana_tipo(), 10);
+        let transporte =
+            calcular_transporte_por_perfil(11, 2024, Money::from_reais(5), &perfil).unwrap();
+        assert_eq!(transporte.viagens_mes, 42);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{Money, ResultadoCalculo};
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+use std::path::Path;
+
+const CABECALHO: [&str; 7] = [
+    "Competência",
+    "Dias trabalhados",
+    "Taxa fixa",
+    "Transporte",
+    "Proventos",
+    "Deduções",
+    "Pagamento final",
+];
+
+const FORMATO_MOEDA: &str = "\"R$\" #,##0.00";
+
+fn em_reais(valor: Money) -> f64 {
+    valor.to_centavos() as f64 / 100.0
+}
+
+fn competencia(resultado: &ResultadoCalculo, indice: usize) -> String {
+    match resultado
+        .calendario
+        .as_ref()
+        .and_then(|c| c.nome_mes.zip(c.ano))
+    {
+        Some((nome_mes, ano)) => format!("{}/{}", nome_mes, ano),
+        None => format!("Competência {}", indice + 1),
+    }
+}
+
+/// Gera uma planilha com uma linha por competência e uma linha de totais
+/// no final. As colunas monetárias usam formato de moeda.
+pub fn exportar_xlsx(resultados: &[ResultadoCalculo], caminho: &Path) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let planilha = workbook.add_worksheet();
+    planilha.set_name("Pagamentos")?;
+
+    let negrito = Format::new().set_bold();
+    let moeda = Format::new().set_num_format(FORMATO_MOEDA);
+    let moeda_negrito = Format::new().set_bold().set_num_format(FORMATO_MOEDA);
+
+    for (coluna, titulo) in CABECALHO.iter().enumerate() {
+        planilha.write_string_with_format(0, coluna as u16, *titulo, &negrito)?;
+    }
+    planilha.set_column_width(0, 20)?;
+
+    for (indice, resultado) in resultados.iter().enumerate() {
+        let linha = indice as u32 + 1;
+        let valores = [
+            resultado.taxa_fixa,
+            resultado.custo_transporte,
+            resultado.proventos_total,
+            resultado.deducoes_total,
+            resultado.pagamento_final,
+        ];
+
+        planilha.write_string(linha, 0, competencia(resultado, indice))?;
+        planilha.write_number(linha, 1, resultado.dias_trabalhados_fracionarios)?;
+        for (coluna, valor) in valores.iter().enumerate() {
+            planilha.write_number_with_format(
+                linha,
+                coluna as u16 + 2,
+                em_reais(*valor),
+                &moeda,
+            )?;
+        }
+    }
+
+    // Totais calculados em centavos para não acumular erro de ponto flutuante
+    let linha_total = resultados.len() as u32 + 1;
+    let total = |campo: fn(&ResultadoCalculo) -> Money| {
+        resultados.iter().fold(Money::ZERO, |acc, r| acc + campo(r))
+    };
+    let totais = [
+        total(|r| r.taxa_fixa),
+        total(|r| r.custo_transporte),
+        total(|r| r.proventos_total),
+        total(|r| r.deducoes_total),
+        total(|r| r.pagamento_final),
+    ];
+    let dias: f64 = resultados
+        .iter()
+        .map(|r| r.dias_trabalhados_fracionarios)
+        .sum();
+
+    planilha.write_string_with_format(linha_total, 0, "Total", &negrito)?;
+    planilha.write_number_with_format(linha_total, 1, dias, &negrito)?;
+    for (coluna, valor) in totais.iter().enumerate() {
+        planilha.write_number_with_format(
+            linha_total,
+            coluna as u16 + 2,
+            em_reais(*valor),
+            &moeda_negrito,
+        )?;
+    }
+
+    workbook.save(caminho)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calcular_valores_com_calendario;
+    use std::io::Read;
+
+    fn ler_planilha(caminho: &Path) -> String {
+        let arquivo = std::fs::File::open(caminho).unwrap();
+        let mut zip = zip::ZipArchive::new(arquivo).unwrap();
+        let mut xml = String::new();
+        zip.by_name("xl/worksheets/sheet1.xml")
+            .unwrap()
+            .read_to_string(&mut xml)
+            .unwrap();
+        xml
+    }
+
+    #[test]
+    fn test_exportar_xlsx() {
+        let resultados: Vec<_> = (1..=3)
+            .map(|mes| {
+                calcular_valores_com_calendario(
+                    Money::from_reais(150),
+                    Money::parse("7.50").unwrap(),
+                    mes,
+                    2024,
+                    0,
+                    vec![],
+                )
+                .unwrap()
+            })
+            .collect();
+        let caminho = std::env::temp_dir().join(format!(
+            "calculadora_exportar_xlsx_{}.xlsx",
+            std::process::id()
+        ));
+
+        exportar_xlsx(&resultados, &caminho).unwrap();
+        let xml = ler_planilha(&caminho);
+        std::fs::remove_file(&caminho).unwrap();
+
+        // cabeçalho + 3 competências + totais
+        assert_eq!(xml.matches("<row ").count(), 5);
+        assert!(xml.contains("<c r=\"A5\""));
+    }
+}